@@ -30,7 +30,112 @@ pub enum EditionsError {
     InvalidMetadata,
 
     #[msg("Creator fee too high")]
-    CreatorFeeTooHigh
+    CreatorFeeTooHigh,
+
+    #[msg("Hashlist entry does not match the mint")]
+    HashlistEntryMismatch,
+
+    #[msg("Nothing to burn")]
+    NothingToBurn,
+
+    #[msg("Deployment has no delayed reveal")]
+    NoDelayedReveal,
+
+    #[msg("Deployment has already been revealed")]
+    AlreadyRevealed,
+
+    #[msg("Deployment has not been revealed yet")]
+    NotRevealed,
+
+    #[msg("Revealed uri does not match the provenance hash")]
+    ProvenanceHashMismatch,
+
+    #[msg("Offchain url too long")]
+    UriTooLong,
+
+    #[msg("Only one set of curlies ({{}}) can be specified")]
+    TooManyTemplates,
+
+    #[msg("Random numbering requires a max number of tokens between 1 and 81920")]
+    InvalidRandomNumberingSupply,
+
+    #[msg("Token indices and slot hashes accounts are required for random numbering")]
+    MissingTokenIndices,
+
+    #[msg("Hashlist page does not match the page of this mint")]
+    InvalidHashlistPage,
+
+    #[msg("Name too long")]
+    NameTooLong,
+
+    #[msg("Symbol too long")]
+    SymbolTooLong,
+
+    #[msg("Mint and hashlist marker are required in the remaining accounts for every item")]
+    MissingItemAccounts,
+
+    #[msg("Signer is not the pending creator")]
+    NotPendingCreator,
+
+    #[msg("Creator changed since the proposal")]
+    StaleCreatorProposal,
+
+    #[msg("Deployment requires creator cosign to mint, creator cannot be renounced")]
+    CreatorRequiredForMinting,
+
+    #[msg("Deployment is not live")]
+    DeploymentNotLive,
+
+    #[msg("Invalid deployment status transition")]
+    InvalidStatusTransition,
+
+    #[msg("Deployment is not revocable")]
+    NotRevocable,
+
+    #[msg("Token account and mint are required in the remaining accounts for every edition")]
+    MissingThawAccounts,
+
+    #[msg("Editions of this deployment can only be thawed by the creator")]
+    CreatorThawOnly,
+
+    #[msg("Editions stay frozen until the deployment mints out or closes, or the unlock time passes")]
+    ThawLocked,
+
+    #[msg("Mint end time must come after the start time and cannot be combined with random numbering or a delayed reveal")]
+    InvalidMintWindow,
+
+    #[msg("Mint has not started yet")]
+    MintNotStarted,
+
+    #[msg("Mint window has closed")]
+    MintWindowClosed,
+
+    #[msg("Mint window is still open")]
+    MintWindowOpen,
+
+    #[msg("Max supply of random numbering and delayed reveal deployments cannot change")]
+    MaxSupplyFixed,
+
+    #[msg("Token numbers are drawn from a slot after the mint")]
+    DrawNotReady,
+
+    #[msg("Item uri of a delayed reveal deployment is fixed by its provenance hash")]
+    ItemUriCommitted,
+
+    #[msg("Freezing until mint-out requires a max number of tokens, an unlock time or a mint end time")]
+    NoThawUnlock,
+
+    #[msg("Cannot finalize a deployment that has not minted any editions")]
+    NothingMinted,
+
+    #[msg("Token numbers are drawn in hashlist order, starting from the next one to draw")]
+    DrawOutOfOrder,
+
+    #[msg("Reveal offset is drawn from a slot after the reveal")]
+    RevealNotReady,
+
+    #[msg("Minting resumes once the reveal offset has been drawn")]
+    RevealPending,
 }
 
 #[error_code]
@@ -59,4 +164,24 @@ pub enum MetadataErrors {
     ReservedField,
     #[msg("Invalid number of platform fee recipients. Exactly 5 recipients are required.")]
     InvalidNumberOfRecipients,
+    #[msg("Missing or invalid extra account metas account.")]
+    MissingExtraMetasAccount,
+    #[msg("Transfer hook can only be invoked by a transfer.")]
+    NotTransferring,
+    #[msg("Royalties have not been set on this mint.")]
+    RoyaltiesNotSet,
+    #[msg("Missing or invalid creator account.")]
+    MissingCreatorAccount,
+    #[msg("Payment mint, payer payment account and payment token program must be passed together.")]
+    MissingPaymentAccounts,
+    #[msg("Too many creators.")]
+    TooManyCreators,
+    #[msg("Mint does not belong to the deployment.")]
+    MintNotInDeployment,
+    #[msg("Sale price must be greater than zero.")]
+    ZeroPrice,
+    #[msg("Royalty on this sale price is zero.")]
+    ZeroRoyalty,
+    #[msg("Transfers of this edition must come right after a memo.")]
+    MissingTransferMemo,
 }
\ No newline at end of file
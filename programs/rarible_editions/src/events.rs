@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreatorProposed {
+    pub editions_deployment: Pubkey,
+    pub creator: Pubkey,
+    pub pending_creator: Pubkey,
+}
+
+#[event]
+pub struct CreatorTransferred {
+    pub editions_deployment: Pubkey,
+    pub previous_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct CreatorRenounced {
+    pub editions_deployment: Pubkey,
+    pub previous_creator: Pubkey,
+}
+
+#[event]
+pub struct CreatorVerified {
+    pub editions_deployment: Pubkey,
+    pub creator: Pubkey,
+    pub verified: bool,
+}
+
+#[event]
+pub struct DeploymentFinalized {
+    pub editions_deployment: Pubkey,
+    pub number_of_tokens: u64,
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{self, Burn, CloseAccount, Mint, TokenAccount},
+};
+
+use crate::{errors::EditionsError, mark_hashlist_entry_burned, thaw_if_frozen, EditionsDeployment, HashlistMarker};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct BurnInput {
+    // position of the mint in the hashlist, checked against the stored mint
+    pub hashlist_index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(input: BurnInput)]
+pub struct BurnCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    /// CHECK: Checked in PDA. Not deserialized because it can be rather big
+    #[account(mut, 
+        seeds = ["hashlist".as_bytes(), 
+        editions_deployment.key().as_ref()],
+        bump,)]
+    pub hashlist: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in locate_hashlist_entry, only required for mints recorded in a hashlist page
+    #[account(mut)]
+    pub hashlist_page: Option<UncheckedAccount<'info>>,
+
+    // rent goes back to the holder
+    #[account(mut,
+        close = owner,
+        seeds = ["hashlist_marker".as_bytes(), 
+        editions_deployment.key().as_ref(),
+        mint.key().as_ref()],
+        bump,)]
+    pub hashlist_marker: Account<'info, HashlistMarker>,
+
+    // holder of the edition, receives the rent of the closed token account
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut,
+        token::mint = mint,
+        token::authority = owner)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /* BOILERPLATE PROGRAM ACCOUNTS */
+    /// CHECK: Checked in constraint
+    #[account(
+        constraint = token_program.key() == token_2022::ID
+    )]
+    pub token_program: UncheckedAccount<'info>,
+}
+
+pub fn burn(ctx: Context<BurnCtx>, input: BurnInput) -> Result<()> {
+    let hashlist = &ctx.accounts.hashlist;
+    let mint = &ctx.accounts.mint;
+    let token_account = &ctx.accounts.token_account;
+
+    if token_account.amount == 0 {
+        return Err(EditionsError::NothingToBurn.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        ctx.accounts.editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    // editions frozen until mint-out, or by default, can still be burned by their holder
+    thaw_if_frozen(
+        &token_account.to_account_info(),
+        &mint.to_account_info(),
+        &ctx.accounts.editions_deployment.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        deployment_seeds,
+    )?;
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        token_account.amount,
+    )?;
+
+    // the emptied token account is closed as well, returning its rent to the holder
+    token_interface::close_account(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: token_account.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+    )?;
+
+    // mints with a close authority are closed as well, the edition is gone for good
+    if ctx.accounts.editions_deployment.mint_close_authority {
+        token_interface::close_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: mint.to_account_info(),
+                    destination: ctx.accounts.owner.to_account_info(),
+                    authority: ctx.accounts.editions_deployment.to_account_info(),
+                },
+                &[deployment_seeds],
+            ),
+        )?;
+    }
+
+    let hashlist_page = ctx.accounts.hashlist_page.as_ref().map(|x| x.to_account_info());
+    mark_hashlist_entry_burned(
+        &ctx.accounts.editions_deployment.key(),
+        &hashlist.to_account_info(),
+        hashlist_page.as_ref(),
+        input.hashlist_index,
+        &mint.key(),
+    )?;
+
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+    editions_deployment.number_of_tokens_burned += 1;
+    msg!(
+        "burned {}, supply {} of {} issued",
+        mint.key(),
+        editions_deployment.current_supply(),
+        editions_deployment.number_of_tokens_issued
+    );
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::EditionsError, CreatorTransferred, EditionsDeployment, PendingCreator};
+
+#[derive(Accounts)]
+pub struct AcceptCreatorCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    // rent goes to the new creator
+    #[account(mut,
+        close = new_creator,
+        seeds = ["pending_creator".as_bytes(), editions_deployment.key().as_ref()],
+        bump)]
+    pub pending_creator: Account<'info, PendingCreator>,
+
+    #[account(mut)]
+    pub new_creator: Signer<'info>,
+}
+
+pub fn accept_creator(ctx: Context<AcceptCreatorCtx>) -> Result<()> {
+    let pending_creator = &ctx.accounts.pending_creator;
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+
+    if pending_creator.pending_creator != ctx.accounts.new_creator.key() {
+        return Err(EditionsError::NotPendingCreator.into());
+    }
+    if pending_creator.creator != editions_deployment.creator {
+        return Err(EditionsError::StaleCreatorProposal.into());
+    }
+
+    let previous_creator = editions_deployment.creator;
+    editions_deployment.creator = pending_creator.pending_creator;
+
+    emit!(CreatorTransferred {
+        editions_deployment: editions_deployment.key(),
+        previous_creator,
+        new_creator: editions_deployment.creator,
+    });
+
+    Ok(())
+}
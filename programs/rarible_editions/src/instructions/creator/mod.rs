@@ -0,0 +1,7 @@
+pub mod propose;
+pub mod accept;
+pub mod renounce;
+
+pub use propose::*;
+pub use accept::*;
+pub use renounce::*;
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{CreatorProposed, EditionsDeployment, PendingCreator};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ProposeCreatorInput {
+    pub new_creator: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(input: ProposeCreatorInput)]
+pub struct ProposeCreatorCtx<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    // a new proposal replaces the previous one
+    #[account(init_if_needed,
+        payer = payer,
+        space = PendingCreator::SIZE,
+        seeds = ["pending_creator".as_bytes(), editions_deployment.key().as_ref()],
+        bump)]
+    pub pending_creator: Account<'info, PendingCreator>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = creator.key() == editions_deployment.creator
+    )]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_creator(ctx: Context<ProposeCreatorCtx>, input: ProposeCreatorInput) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    ctx.accounts.pending_creator.set_inner(PendingCreator {
+        editions_deployment: editions_deployment.key(),
+        creator: editions_deployment.creator,
+        pending_creator: input.new_creator,
+    });
+
+    emit!(CreatorProposed {
+        editions_deployment: editions_deployment.key(),
+        creator: editions_deployment.creator,
+        pending_creator: input.new_creator,
+    });
+
+    Ok(())
+}
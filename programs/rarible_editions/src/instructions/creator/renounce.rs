@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use solana_program::system_program;
+
+use crate::{errors::EditionsError, CreatorRenounced, EditionsDeployment};
+
+#[derive(Accounts)]
+pub struct RenounceCreatorCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(
+        constraint = creator.key() == editions_deployment.creator
+    )]
+    pub creator: Signer<'info>,
+}
+
+/*
+    Sets the creator to the default pubkey, which nobody can sign for. Every creator
+    gated instruction (metadata, royalties, reveal, ...) is locked from then on.
+*/
+pub fn renounce_creator(ctx: Context<RenounceCreatorCtx>) -> Result<()> {
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+
+    // mints of cosigned deployments need the creator signature
+    if editions_deployment.cosigner_program_id != system_program::ID {
+        return Err(EditionsError::CreatorRequiredForMinting.into());
+    }
+
+    let previous_creator = editions_deployment.creator;
+    editions_deployment.creator = Pubkey::default();
+
+    emit!(CreatorRenounced {
+        editions_deployment: editions_deployment.key(),
+        previous_creator,
+    });
+
+    Ok(())
+}
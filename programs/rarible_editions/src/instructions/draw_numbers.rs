@@ -0,0 +1,171 @@
+use anchor_lang::{prelude::*, solana_program::{hash::hashv, sysvar::slot_hashes}};
+use anchor_spl::{
+    token_2022,
+    token_2022_extensions::{token_metadata_update_field, TokenMetadataUpdateField},
+};
+use spl_token_metadata_interface::state::Field;
+
+use crate::{errors::EditionsError, get_slot_hash, locate_hashlist_entry, take_random_index, utils::update_account_lamports_to_minimum_balance, EditionsDeployment, BURNED_ORDER_FLAG, PENDING_DRAW_FLAG};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct DrawNumbersInput {
+    // hashlist position of every mint passed in the remaining accounts, in the same order.
+    // consecutive, from editions_deployment.next_draw_index (positions before it are skipped)
+    pub hashlist_indices: Vec<u32>,
+}
+
+#[derive(Accounts)]
+#[instruction(input: DrawNumbersInput)]
+pub struct DrawNumbersCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    /// CHECK: Checked in PDA. Not deserialized because it can be rather big
+    #[account(
+        seeds = ["hashlist".as_bytes(),
+        editions_deployment.key().as_ref()],
+        bump,)]
+    pub hashlist: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in locate_hashlist_entry. random numbering mints are always recorded in hashlist pages
+    #[account(mut)]
+    pub hashlist_page: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in PDA
+    #[account(mut,
+        seeds = ["token_indices".as_bytes(),
+        editions_deployment.key().as_ref()],
+        bump,)]
+    pub token_indices: UncheckedAccount<'info>,
+
+    /// CHECK: address checked
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    // tops up mint rent when the name or uri grows
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Checked in constraint
+    #[account(
+        constraint = token_program.key() == token_2022::ID
+    )]
+    pub token_program: UncheckedAccount<'info>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+}
+
+/*
+    Permissionless. Draws the token number of random numbering mints from the hash of the slot they
+    were minted in, which was unknown when the mint was sent, and writes it to their name, uri and
+    hashlist entry. Mints are passed in the remaining accounts, each checked against its hashlist
+    entry, and must all come from the hashlist page passed in.
+
+    Numbers are drawn strictly in hashlist order, so that every number only depends on slot hashes
+    and not on the order draws are sent in. The slot hashes sysvar only keeps 512 slots (a few
+    minutes), so numbers should be drawn right after minting. A mint whose slot has left the sysvar
+    is committed to the current slot instead, and drawn from its hash by a later draw_numbers.
+*/
+pub fn draw_numbers<'info>(ctx: Context<'_, '_, '_, 'info, DrawNumbersCtx<'info>>, input: DrawNumbersInput) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    if input.hashlist_indices.len() != ctx.remaining_accounts.len() {
+        return Err(EditionsError::HashlistEntryMismatch.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    let current_slot = Clock::get()?.slot;
+    let hashlist_page = ctx.accounts.hashlist_page.to_account_info();
+    let mut next_draw_index = editions_deployment.next_draw_index;
+    for (mint, hashlist_index) in ctx.remaining_accounts.iter().zip(input.hashlist_indices.iter()) {
+        // already settled
+        if *hashlist_index < next_draw_index {
+            continue;
+        }
+        if *hashlist_index != next_draw_index {
+            return Err(EditionsError::DrawOutOfOrder.into());
+        }
+
+        let (hashlist_account_info, mint_start_pos) = locate_hashlist_entry(
+            &editions_deployment.key(),
+            &ctx.accounts.hashlist.to_account_info(),
+            Some(&hashlist_page),
+            *hashlist_index,
+        )?;
+        let order = {
+            let hashlist_data = hashlist_account_info.try_borrow_data()?;
+            if hashlist_data[mint_start_pos..mint_start_pos + 32] != mint.key().to_bytes() {
+                return Err(EditionsError::HashlistEntryMismatch.into());
+            }
+            u64::from_le_bytes(hashlist_data[mint_start_pos + 32..mint_start_pos + 40].try_into().unwrap())
+        };
+
+        // already drawn, or burned before its number was drawn
+        if order & PENDING_DRAW_FLAG == 0 || order & BURNED_ORDER_FLAG != 0 {
+            next_draw_index += 1;
+            continue;
+        }
+
+        let mint_slot = order & !PENDING_DRAW_FLAG;
+        if current_slot <= mint_slot {
+            return Err(EditionsError::DrawNotReady.into());
+        }
+
+        let Some(slot_hash) = get_slot_hash(&ctx.accounts.slot_hashes.to_account_info(), mint_slot)? else {
+            // the hash is gone. the current slot's hash is not known yet either, so whoever sends
+            // this cannot tell which number the mint will get
+            hashlist_account_info.try_borrow_mut_data()?[mint_start_pos + 32..mint_start_pos + 40]
+                .copy_from_slice(&(PENDING_DRAW_FLAG | current_slot).to_le_bytes());
+            break;
+        };
+
+        // the hashlist index tells apart mints sent in the same slot
+        let seed = hashv(&[&slot_hash, &hashlist_index.to_le_bytes()]);
+        let token_number = take_random_index(
+            &ctx.accounts.token_indices.to_account_info(),
+            u64::from_le_bytes(seed.to_bytes()[..8].try_into().unwrap()),
+            editions_deployment.max_number_of_tokens,
+        )?;
+
+        hashlist_account_info.try_borrow_mut_data()?[mint_start_pos + 32..mint_start_pos + 40]
+            .copy_from_slice(&token_number.to_le_bytes());
+
+        for (field, value) in [
+            (Field::Name, editions_deployment.item_name(Some(token_number))),
+            (Field::Uri, editions_deployment.item_uri(Some(token_number))),
+        ] {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        token_program_id: ctx.accounts.token_program.to_account_info(),
+                        metadata: mint.clone(),
+                        update_authority: editions_deployment.to_account_info(),
+                    },
+                    &[deployment_seeds],
+                ),
+                field,
+                value,
+            )?;
+        }
+
+        update_account_lamports_to_minimum_balance(
+            mint.clone(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        next_draw_index += 1;
+    }
+
+    ctx.accounts.editions_deployment.next_draw_index = next_draw_index;
+
+    Ok(())
+}
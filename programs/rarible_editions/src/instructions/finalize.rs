@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::EditionsError, group_extension_program, update_group_max_size, DeploymentFinalized, DeploymentStatus, EditionsDeployment};
+
+#[derive(Accounts)]
+pub struct FinalizeCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    /// CHECK: Checked in constraint
+    #[account(mut,
+        constraint = editions_deployment.group == group.key())]
+    pub group: UncheckedAccount<'info>,
+
+    /// CHECK: address checked
+    #[account(address = group_extension_program::ID)]
+    pub group_extension_program: AccountInfo<'info>,
+}
+
+/*
+    Permissionless: once the mint window of a deployment has closed, fixes its supply at the
+    number of tokens issued, both on the deployment and as the max size of the group.
+*/
+pub fn finalize(ctx: Context<FinalizeCtx>) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    if editions_deployment.mint_end_time == 0 || Clock::get()?.unix_timestamp <= editions_deployment.mint_end_time {
+        return Err(EditionsError::MintWindowOpen.into());
+    }
+    if matches!(editions_deployment.status, DeploymentStatus::MintedOut | DeploymentStatus::Closed) {
+        return Err(EditionsError::InvalidStatusTransition.into());
+    }
+    // a max of 0 would turn the deployment into an open edition
+    if editions_deployment.number_of_tokens_issued == 0 {
+        return Err(EditionsError::NothingMinted.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    // the group size counts burned editions too, so it always equals number_of_tokens_issued
+    update_group_max_size(
+        &ctx.accounts.group.to_account_info(),
+        &editions_deployment.to_account_info(),
+        &ctx.accounts.group_extension_program.to_account_info(),
+        deployment_seeds,
+        editions_deployment.number_of_tokens_issued as u32,
+    )?;
+
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+    editions_deployment.max_number_of_tokens = editions_deployment.number_of_tokens_issued;
+    editions_deployment.status = DeploymentStatus::MintedOut;
+
+    emit!(DeploymentFinalized {
+        editions_deployment: editions_deployment.key(),
+        number_of_tokens: editions_deployment.max_number_of_tokens,
+    });
+
+    Ok(())
+}
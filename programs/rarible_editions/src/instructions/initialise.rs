@@ -1,4 +1,4 @@
-use crate::{group_extension_program, utils::update_account_lamports_to_minimum_balance, EditionsDeployment, Hashlist, NAME_LIMIT, URI_LIMIT, SYMBOL_LIMIT};
+use crate::{create_token_indices, errors::EditionsError, DeploymentStatus, group_extension_program, utils::update_account_lamports_to_minimum_balance, EditionsDeployment, Hashlist, MAX_RANDOM_NUMBERING_TOKENS, NAME_LIMIT, URI_LIMIT, SYMBOL_LIMIT};
 use anchor_lang::prelude::*;
 use libreplex_shared::{create_token_2022_and_metadata, MintAccounts2022, TokenGroupInput};
 use solana_program::system_program;
@@ -20,6 +20,31 @@ pub struct InitialiseInput {
     // hippo #{} -> turns into hippo #0, hippo #1, etc
     // without curlies the url is the same for all mints 
     pub item_base_name: String,
+    // sha256 of the final item_base_uri for a delayed reveal, item_base_uri is then the placeholder
+    pub provenance_hash: Option<[u8; 32]>,
+    // draw token numbers at random instead of sequentially. requires a max number of tokens
+    pub random_numbering: bool,
+    // mint editions with a transfer hook that rejects transfers until royalties are paid
+    pub enforce_royalties: bool,
+    // mint editions non-transferable, for badges and credentials
+    pub soulbound: bool,
+    // make the deployment permanent delegate of every edition so that the creator can revoke them
+    pub revocable: bool,
+    // let the deployment close edition mints once burned
+    pub mint_close_authority: bool,
+    // token accounts of editions start out frozen, except the one of the minter
+    pub default_account_frozen: bool,
+    // reject transfers of editions that are not preceded by a memo instruction. MemoTransfer only
+    // exists for token accounts and is up to each holder, so this goes through the transfer hook
+    pub require_transfer_memo: bool,
+    // freeze minted editions until the deployment mints out or unlock_time passes
+    pub freeze_until_mint_out: bool,
+    // unix timestamp after which frozen editions can be thawed regardless of mint-out
+    pub unlock_time: Option<i64>,
+    // unix timestamps bounding the mint. with an end time the deployment is finalized once closed,
+    // fixing the supply at the number of tokens issued
+    pub mint_start_time: Option<i64>,
+    pub mint_end_time: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -48,6 +73,10 @@ pub struct InitialiseCtx<'info> {
     #[account(mut)]
     pub group: Signer<'info>,
 
+    /// CHECK: created in create_token_indices, only required for random numbering
+    #[account(mut)]
+    pub token_indices: Option<UncheckedAccount<'info>>,
+
     #[account()]
     pub system_program: Program<'info, System>,
 
@@ -71,6 +100,29 @@ pub fn initialise(ctx: Context<InitialiseCtx>, input: InitialiseInput) -> Result
         panic!("Offchain url too long");
     }
 
+    if input.random_numbering
+        && (input.max_number_of_tokens == 0 || input.max_number_of_tokens > MAX_RANDOM_NUMBERING_TOKENS) {
+        return Err(EditionsError::InvalidRandomNumberingSupply.into());
+    }
+
+    // random numbers are drawn up to max_number_of_tokens and revealed item numbers wrap around it,
+    // both of which finalize would cut short
+    if let Some(mint_end_time) = input.mint_end_time {
+        if input.random_numbering
+            || input.provenance_hash.is_some()
+            || mint_end_time <= input.mint_start_time.unwrap_or(0) {
+            return Err(EditionsError::InvalidMintWindow.into());
+        }
+    }
+
+    // editions frozen until mint-out would stay frozen forever without something to unlock them
+    if input.freeze_until_mint_out
+        && input.max_number_of_tokens == 0
+        && input.unlock_time.is_none()
+        && input.mint_end_time.is_none() {
+        return Err(EditionsError::NoThawUnlock.into());
+    }
+
     let group_mint = &ctx.accounts.group_mint;
 
     let group = &ctx.accounts.group;
@@ -106,9 +158,38 @@ pub fn initialise(ctx: Context<InitialiseCtx>, input: InitialiseInput) -> Result
         item_base_uri: input.item_base_uri,
         item_name_is_template,
         item_uri_is_template,
-        padding: [0; 98],
+        provenance_hash: input.provenance_hash.unwrap_or([0; 32]),
+        reveal_offset: 0,
+        is_revealed: false,
+        random_numbering: input.random_numbering,
+        number_of_tokens_burned: 0,
+        status: DeploymentStatus::Live,
+        enforce_royalties: input.enforce_royalties,
+        soulbound: input.soulbound,
+        revocable: input.revocable,
+        mint_close_authority: input.mint_close_authority,
+        default_account_frozen: input.default_account_frozen,
+        freeze_until_mint_out: input.freeze_until_mint_out,
+        unlock_time: input.unlock_time.unwrap_or(0),
+        mint_start_time: input.mint_start_time.unwrap_or(0),
+        mint_end_time: input.mint_end_time.unwrap_or(0),
+        require_transfer_memo: input.require_transfer_memo,
+        next_draw_index: 0,
+        reveal_slot: 0,
+        padding: [0; 8],
     });
 
+    if input.random_numbering {
+        let token_indices = ctx.accounts.token_indices.as_ref().ok_or(EditionsError::MissingTokenIndices)?;
+        create_token_indices(
+            &token_indices.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.editions_deployment.key(),
+            input.max_number_of_tokens,
+        )?;
+    }
+
     let editions_deployment = &ctx.accounts.editions_deployment;
     let payer = &ctx.accounts.payer;
     let group_mint = &ctx.accounts.group_mint;
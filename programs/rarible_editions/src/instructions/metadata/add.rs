@@ -1,6 +1,6 @@
 use anchor_lang::{prelude::*, solana_program::entrypoint::ProgramResult};
 use std::str::FromStr;
-use crate::{ROYALTY_BASIS_POINTS_FIELD};
+use crate::{CREATOR_FIELD_PREFIX, ROYALTY_BASIS_POINTS_FIELD};
 
 use anchor_spl::token_interface::{
     spl_token_metadata_interface::state::Field, token_metadata_update_field, Token2022,
@@ -65,6 +65,7 @@ pub fn handler(ctx: Context<AddMetadata>, args: Vec<AddMetadataArgs>) -> Result<
 
         // Validate that the field does not start with reserved prefixes
         if metadata_arg.field.starts_with(ROYALTY_BASIS_POINTS_FIELD)
+            || metadata_arg.field.starts_with(CREATOR_FIELD_PREFIX)
         {
             return Err(MetadataErrors::InvalidField.into());
         }
@@ -1,5 +1,9 @@
 pub mod add;
 pub mod remove;
+pub mod update_collection;
+pub mod update_item;
 
 pub use add::*;
 pub use remove::*;
+pub use update_collection::*;
+pub use update_item::*;
@@ -7,7 +7,7 @@ use anchor_spl::{
     token_interface::{Mint, Token2022},
 };
 use solana_program::program::invoke_signed;
-use crate::{errors::MetadataErrors, EditionsDeployment, ROYALTY_BASIS_POINTS_FIELD};
+use crate::{errors::MetadataErrors, EditionsDeployment, CREATOR_FIELD_PREFIX, ROYALTY_BASIS_POINTS_FIELD};
 use crate::utils::update_account_lamports_to_minimum_balance;
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -73,6 +73,7 @@ pub fn handler(ctx: Context<RemoveMetadata>, args: Vec<RemoveMetadataArgs>) -> R
 
         // Validate that the field does not start with reserved prefixes
         if metadata_arg.field.starts_with(ROYALTY_BASIS_POINTS_FIELD)
+            || metadata_arg.field.starts_with(CREATOR_FIELD_PREFIX)
         {
             return Err(MetadataErrors::InvalidField.into());
         }
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Token2022,
+    TokenMetadataUpdateField,
+};
+
+use crate::utils::update_account_lamports_to_minimum_balance;
+use crate::{errors::EditionsError, EditionsDeployment, NAME_LIMIT, SYMBOL_LIMIT, URI_LIMIT};
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateCollectionMetadataArgs {
+    pub name: Option<String>,
+    // only changes the group mint metadata, the deployment keeps the symbol it is derived from
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    // templates used for items minted from now on, see InitialiseInput
+    pub item_base_name: Option<String>,
+    pub item_base_uri: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollectionMetadata<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = signer.key() == editions_deployment.creator
+    )]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Checked in constraint
+    #[account(mut,
+        constraint = editions_deployment.group_mint == group_mint.key())]
+    pub group_mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn update_collection_metadata(ctx: Context<UpdateCollectionMetadata>, args: UpdateCollectionMetadataArgs) -> Result<()> {
+    let mut fields: Vec<(Field, String)> = vec![];
+
+    if let Some(name) = args.name {
+        if name.len() > NAME_LIMIT {
+            return Err(EditionsError::NameTooLong.into());
+        }
+        fields.push((Field::Name, name));
+    }
+    if let Some(symbol) = args.symbol {
+        if symbol.len() > SYMBOL_LIMIT {
+            return Err(EditionsError::SymbolTooLong.into());
+        }
+        fields.push((Field::Symbol, symbol));
+    }
+    if let Some(uri) = args.uri {
+        if uri.len() > URI_LIMIT {
+            return Err(EditionsError::UriTooLong.into());
+        }
+        fields.push((Field::Uri, uri));
+    }
+
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+
+    if let Some(item_base_name) = args.item_base_name {
+        if item_base_name.len() > NAME_LIMIT {
+            return Err(EditionsError::NameTooLong.into());
+        }
+        editions_deployment.item_name_is_template = is_template(&item_base_name)?;
+        editions_deployment.item_base_name = item_base_name;
+    }
+    if let Some(item_base_uri) = args.item_base_uri {
+        // the placeholder and the revealed uri are both fixed by the provenance hash
+        if editions_deployment.has_delayed_reveal() {
+            return Err(EditionsError::ItemUriCommitted.into());
+        }
+        if item_base_uri.len() > URI_LIMIT {
+            return Err(EditionsError::UriTooLong.into());
+        }
+        editions_deployment.item_uri_is_template = is_template(&item_base_uri)?;
+        editions_deployment.item_base_uri = item_base_uri;
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    for (field, value) in fields {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.group_mint.to_account_info(),
+                    update_authority: editions_deployment.to_account_info(),
+                },
+                &[deployment_seeds],
+            ),
+            field,
+            value,
+        )?;
+    }
+
+    // transfer minimum rent to group mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.group_mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
+
+fn is_template(value: &str) -> Result<bool> {
+    match value.matches("{}").count() {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(EditionsError::TooManyTemplates.into()),
+    }
+}
@@ -0,0 +1,189 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::Field, token_metadata_update_field, Mint, Token2022,
+    TokenMetadataUpdateField,
+};
+
+use crate::utils::update_account_lamports_to_minimum_balance;
+use crate::{
+    errors::{EditionsError, MetadataErrors},
+    AddMetadataArgs, EditionsDeployment, HashlistMarker, CREATOR_FIELD_PREFIX, NAME_LIMIT, ROYALTY_BASIS_POINTS_FIELD, URI_LIMIT,
+};
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateItemMetadataArgs {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+    // added, or overwritten when the key already exists
+    pub additional_metadata: Vec<AddMetadataArgs>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateItemMetadata<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    // proves that the mint belongs to the deployment
+    #[account(
+        seeds = ["hashlist_marker".as_bytes(),
+        editions_deployment.key().as_ref(),
+        mint.key().as_ref()],
+        bump,)]
+    pub hashlist_marker: Account<'info, HashlistMarker>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = signer.key() == editions_deployment.creator
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateItemMetadataBatch<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = signer.key() == editions_deployment.creator
+    )]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn update_item_metadata(ctx: Context<UpdateItemMetadata>, args: UpdateItemMetadataArgs) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    update_item_fields(
+        editions_deployment,
+        ctx.bumps.editions_deployment,
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        args,
+    )?;
+
+    // transfer minimum rent to mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
+
+/*
+    Updates several items at once. Every item passes its mint and hashlist marker
+    in the remaining accounts, in the same order as args.
+*/
+pub fn update_item_metadata_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateItemMetadataBatch<'info>>,
+    args: Vec<UpdateItemMetadataArgs>,
+) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    if ctx.remaining_accounts.len() != args.len() * 2 {
+        return Err(EditionsError::MissingItemAccounts.into());
+    }
+
+    for (accounts, item_args) in ctx.remaining_accounts.chunks(2).zip(args) {
+        let mint = &accounts[0];
+        let hashlist_marker = &accounts[1];
+
+        let (expected_marker, _) = Pubkey::find_program_address(
+            &["hashlist_marker".as_bytes(), editions_deployment.key().as_ref(), mint.key().as_ref()],
+            &crate::ID,
+        );
+        if hashlist_marker.key() != expected_marker || hashlist_marker.owner != &crate::ID {
+            return Err(EditionsError::HashlistEntryMismatch.into());
+        }
+
+        update_item_fields(
+            editions_deployment,
+            ctx.bumps.editions_deployment,
+            mint,
+            &ctx.accounts.token_program.to_account_info(),
+            item_args,
+        )?;
+
+        update_account_lamports_to_minimum_balance(
+            mint.clone(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn update_item_fields<'info>(
+    editions_deployment: &Account<'info, EditionsDeployment>,
+    bump_edition: u8,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    args: UpdateItemMetadataArgs,
+) -> Result<()> {
+    let mut fields: Vec<(Field, String)> = vec![];
+
+    if let Some(name) = args.name {
+        if name.len() > NAME_LIMIT {
+            return Err(EditionsError::NameTooLong.into());
+        }
+        fields.push((Field::Name, name));
+    }
+    if let Some(uri) = args.uri {
+        if uri.len() > URI_LIMIT {
+            return Err(EditionsError::UriTooLong.into());
+        }
+        fields.push((Field::Uri, uri));
+    }
+    for metadata_arg in args.additional_metadata {
+        // creator and royalty fields are managed by the royalty instructions
+        if Pubkey::from_str(&metadata_arg.field).is_ok()
+            || metadata_arg.field.starts_with(ROYALTY_BASIS_POINTS_FIELD)
+            || metadata_arg.field.starts_with(CREATOR_FIELD_PREFIX) {
+            return Err(MetadataErrors::InvalidField.into());
+        }
+        fields.push((Field::Key(metadata_arg.field), metadata_arg.value));
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[bump_edition],
+    ];
+
+    for (field, value) in fields {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    token_program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: editions_deployment.to_account_info(),
+                },
+                &[deployment_seeds],
+            ),
+            field,
+            value,
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,15 +1,15 @@
 use anchor_lang::{prelude::*, system_program};
 use anchor_spl::token_2022_extensions::{token_metadata_update_field, TokenMetadataUpdateField};
 use anchor_spl::{
-    associated_token::AssociatedToken, token_2022,
+    associated_token::{create_idempotent, AssociatedToken, Create},
+    token_2022::{self, freeze_account, thaw_account, FreezeAccount, ThawAccount},
 };
-use dyn_fmt::AsStrFormatExt;
 use libreplex_shared::{create_token_2022_and_metadata, operations::mint_non_fungible_2022_logic, MintAccounts2022, SharedError, TokenMemberInput};
 use spl_pod::optional_keys::OptionalNonZeroPubkey;
 use spl_token_metadata_interface::state::{Field, TokenMetadata};
 
-use crate::utils::{get_mint_metadata, update_account_lamports_to_minimum_balance};
-use crate::{add_to_hashlist, errors::EditionsError, group_extension_program, EditionsDeployment, HashlistMarker};
+use crate::utils::{create_extra_metas_account, get_mint_metadata, update_account_lamports_to_minimum_balance};
+use crate::{add_to_hashlist, create_edition_mint, create_hashlist_page, errors::{EditionsError, MetadataErrors}, EditionMintAccounts, EditionMintExtensions, get_hashlist_len, get_hashlist_page_address, get_hashlist_page_position, group_extension_program, DeploymentStatus, EditionsDeployment, HashlistMarker, PENDING_DRAW_FLAG};
 
 #[derive(Accounts)]
 pub struct MintCtx<'info> {
@@ -24,6 +24,10 @@ pub struct MintCtx<'info> {
         bump,)]
     pub hashlist: UncheckedAccount<'info>,
 
+    /// CHECK: Checked in mint against the page the next mint falls into, created on first use
+    #[account(mut)]
+    pub hashlist_page: UncheckedAccount<'info>,
+
     #[account(init, 
         space = HashlistMarker::SIZE,
         payer = payer,
@@ -69,6 +73,10 @@ pub struct MintCtx<'info> {
     #[account(mut)]
     pub token_account: UncheckedAccount<'info>,
 
+    /// CHECK: created in create_extra_metas_account, only required when editions have a transfer hook
+    #[account(mut)]
+    pub extra_metas_account: Option<UncheckedAccount<'info>>,
+
     /* BOILERPLATE PROGRAM ACCOUNTS */
     /// CHECK: Checked in constraint
     #[account(
@@ -108,17 +116,35 @@ pub fn mint<'info>(ctx: Context<'_, '_, '_, 'info, MintCtx<'info>>) -> Result<()
     let group_extension_program = &ctx.accounts.group_extension_program;
     // mutable borrows
     let editions_deployment = &mut ctx.accounts.editions_deployment;
-    let hashlist = &mut ctx.accounts.hashlist;
+    let hashlist = &ctx.accounts.hashlist;
+    let hashlist_page = &mut ctx.accounts.hashlist_page;
 
     if !editions_deployment.cosigner_program_id.eq(&system_program::ID) && !signer.key().eq(&editions_deployment.creator.key()) {
         return Err(SharedError::InvalidCreatorCosigner.into());
     }
 
     // max_number_of_tokens == 0 means unlimited mints 
-    if editions_deployment.max_number_of_tokens > 0 && editions_deployment.number_of_tokens_issued >= editions_deployment.max_number_of_tokens {
+    if editions_deployment.status == DeploymentStatus::MintedOut
+        || (editions_deployment.max_number_of_tokens > 0 && editions_deployment.number_of_tokens_issued >= editions_deployment.max_number_of_tokens) {
         return Err(EditionsError::MintedOut.into());
     }
 
+    if editions_deployment.status != DeploymentStatus::Live {
+        return Err(EditionsError::DeploymentNotLive.into());
+    }
+
+    if editions_deployment.is_reveal_pending() {
+        return Err(EditionsError::RevealPending.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if editions_deployment.mint_start_time > 0 && now < editions_deployment.mint_start_time {
+        return Err(EditionsError::MintNotStarted.into());
+    }
+    if editions_deployment.mint_end_time > 0 && now > editions_deployment.mint_end_time {
+        return Err(EditionsError::MintWindowClosed.into());
+    }
+
     let update_authority =
         OptionalNonZeroPubkey::try_from(Some(editions_deployment.key())).expect("Bad update auth");
 
@@ -128,42 +154,113 @@ pub fn mint<'info>(ctx: Context<'_, '_, '_, 'info, MintCtx<'info>>) -> Result<()
         &[ctx.bumps.editions_deployment],
     ];
 
-    let item_name = match editions_deployment.item_name_is_template {
-        true => editions_deployment.item_base_name.format(&[editions_deployment.number_of_tokens_issued + 1]),
-        false => editions_deployment.item_base_name.clone()
+    // sequential unless the deployment draws its numbers at random. random numbers are drawn by
+    // draw_numbers from the hash of this slot, which nobody knows yet when the mint is sent
+    let (token_number, hashlist_order) = match editions_deployment.random_numbering {
+        true => (None, PENDING_DRAW_FLAG | Clock::get()?.slot),
+        false => {
+            let token_number = editions_deployment.number_of_tokens_issued + 1;
+            (Some(token_number), token_number)
+        }
     };
 
-    let item_url = match editions_deployment.item_uri_is_template {
-        true => editions_deployment.item_base_uri.format(&[editions_deployment.number_of_tokens_issued + 1]),
-        false => editions_deployment.item_base_uri.clone()
+    let item_name = editions_deployment.item_name(token_number);
+    let item_url = editions_deployment.item_uri(token_number);
+
+    let token_metadata = TokenMetadata {
+        name: item_name,
+        symbol: editions_deployment.symbol.clone(),
+        uri: item_url,
+        update_authority,
+        mint: mint.key(),
+        additional_metadata: vec![],
     };
 
-    create_token_2022_and_metadata(
-        MintAccounts2022 {
-            authority: editions_deployment.to_account_info(),
-            payer: payer.to_account_info(),
-            nft_owner: minter.to_account_info(),
-            nft_mint: mint.to_account_info(),
-            spl_token_program: token_program.to_account_info(),
-        },
-        0,
-        Some(TokenMetadata {
-            name: item_name,
-            symbol: editions_deployment.symbol.clone(),
-            uri: item_url,
-            update_authority,
-            mint: mint.key(),
-            additional_metadata: vec![],
-        }),
-        None,
-        Some(TokenMemberInput {
-            member: member.to_account_info(),
-            group: group.to_account_info(),
-        }),
-        Some(deployment_seeds),
-        None,
-        Some(group_extension_program.key()),
-    )?;
+    // these extensions have to be initialised before the mint, which create_token_2022_and_metadata does not allow
+    if editions_deployment.has_mint_extensions() {
+        let editions_deployment_key = editions_deployment.key();
+
+        create_edition_mint(
+            EditionMintAccounts {
+                mint: &mint.to_account_info(),
+                member: &member.to_account_info(),
+                group: &group.to_account_info(),
+                editions_deployment: &editions_deployment.to_account_info(),
+                payer: &payer.to_account_info(),
+                token_program: &token_program.to_account_info(),
+                system_program: &system_program.to_account_info(),
+                group_extension_program: &group_extension_program.to_account_info(),
+            },
+            token_metadata,
+            &EditionMintExtensions {
+                transfer_hook_program_id: editions_deployment.has_transfer_hook().then_some(crate::ID),
+                non_transferable: editions_deployment.soulbound,
+                permanent_delegate: editions_deployment.revocable.then_some(editions_deployment_key),
+                close_authority: editions_deployment.mint_close_authority.then_some(editions_deployment_key),
+                default_account_frozen: editions_deployment.default_account_frozen,
+            },
+            deployment_seeds,
+        )?;
+
+        if editions_deployment.has_transfer_hook() {
+            let extra_metas_account = ctx.accounts.extra_metas_account.as_ref().ok_or(MetadataErrors::MissingExtraMetasAccount)?;
+            create_extra_metas_account(
+                &extra_metas_account.to_account_info(),
+                &mint.key(),
+                &editions_deployment_key,
+                &payer.to_account_info(),
+                &system_program.to_account_info(),
+            )?;
+        }
+    } else {
+        create_token_2022_and_metadata(
+            MintAccounts2022 {
+                authority: editions_deployment.to_account_info(),
+                payer: payer.to_account_info(),
+                nft_owner: minter.to_account_info(),
+                nft_mint: mint.to_account_info(),
+                spl_token_program: token_program.to_account_info(),
+            },
+            0,
+            Some(token_metadata),
+            None,
+            Some(TokenMemberInput {
+                member: member.to_account_info(),
+                group: group.to_account_info(),
+            }),
+            Some(deployment_seeds),
+            None,
+            Some(group_extension_program.key()),
+        )?;
+    }
+
+    // the minter's token account is created frozen like any other, so it is thawed before receiving the edition
+    if editions_deployment.default_account_frozen {
+        create_idempotent(
+            CpiContext::new(
+                associated_token_program.to_account_info(),
+                Create {
+                    payer: payer.to_account_info(),
+                    associated_token: minter_token_account.to_account_info(),
+                    authority: minter.to_account_info(),
+                    mint: mint.to_account_info(),
+                    system_program: system_program.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                },
+            ),
+        )?;
+        thaw_account(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                ThawAccount {
+                    account: minter_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    authority: editions_deployment.to_account_info(),
+                },
+                &[deployment_seeds],
+            ),
+        )?;
+    }
 
     mint_non_fungible_2022_logic(
         &mint.to_account_info(),
@@ -177,14 +274,54 @@ pub fn mint<'info>(ctx: Context<'_, '_, '_, 'info, MintCtx<'info>>) -> Result<()
         deployment_seeds,
     )?;
 
+    // the original hashlist stopped growing when pages were introduced, new mints go to the pages
+    let legacy_hashlist_len = get_hashlist_len(&hashlist.to_account_info())?;
+    let (page_index, page_position) = get_hashlist_page_position(
+        editions_deployment.number_of_tokens_issued.saturating_sub(legacy_hashlist_len as u64)
+    );
+    if hashlist_page.key() != get_hashlist_page_address(&editions_deployment.key(), page_index).0 {
+        return Err(EditionsError::InvalidHashlistPage.into());
+    }
+    if page_position == 0 && hashlist_page.owner != &crate::ID {
+        create_hashlist_page(
+            &hashlist_page.to_account_info(),
+            &payer.to_account_info(),
+            &system_program.to_account_info(),
+            &editions_deployment.key(),
+            page_index,
+        )?;
+    }
+
+    // no wash trading during the drop. the last mint unlocks everything, so its edition is left thawed
+    let is_last_mint = editions_deployment.max_number_of_tokens > 0
+        && editions_deployment.number_of_tokens_issued + 1 >= editions_deployment.max_number_of_tokens;
+    if editions_deployment.freeze_until_mint_out
+        && !is_last_mint
+        && !editions_deployment.is_thaw_unlocked(now) {
+        freeze_account(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                FreezeAccount {
+                    account: minter_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    authority: editions_deployment.to_account_info(),
+                },
+                &[deployment_seeds],
+            ),
+        )?;
+    }
+
     editions_deployment.number_of_tokens_issued += 1;
+    if editions_deployment.max_number_of_tokens > 0 && editions_deployment.number_of_tokens_issued >= editions_deployment.max_number_of_tokens {
+        editions_deployment.status = DeploymentStatus::MintedOut;
+    }
     add_to_hashlist(
-        editions_deployment.number_of_tokens_issued as u32,
-        hashlist,
+        page_position + 1,
+        hashlist_page,
         payer,
         system_program,
         &mint.key(),
-        editions_deployment.number_of_tokens_issued,
+        hashlist_order,
     )?;
 
     // Retrieve metadata from the group mint
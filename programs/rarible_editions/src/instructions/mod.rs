@@ -6,6 +6,15 @@
 pub mod initialise;
 pub use initialise::*;
 
+pub mod set_status;
+pub use set_status::*;
+
+pub mod update_max_supply;
+pub use update_max_supply::*;
+
+pub mod creator;
+pub use creator::*;
+
 pub mod royalties;
 pub use royalties::*;
 
@@ -13,4 +22,28 @@ pub mod metadata;
 pub use metadata::*;
 
 pub mod mint;
-pub use mint::*;
\ No newline at end of file
+pub use mint::*;
+
+pub mod finalize;
+pub use finalize::*;
+
+pub mod burn;
+pub use burn::*;
+
+pub mod revoke;
+pub use revoke::*;
+
+pub mod thaw_editions;
+pub use thaw_editions::*;
+
+pub mod transfer_hook;
+pub use transfer_hook::*;
+
+pub mod reveal;
+pub use reveal::*;
+
+pub mod reveal_items;
+pub use reveal_items::*;
+
+pub mod draw_numbers;
+pub use draw_numbers::*;
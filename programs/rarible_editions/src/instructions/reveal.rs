@@ -0,0 +1,101 @@
+use anchor_lang::{prelude::*, solana_program::{hash::hash, sysvar::slot_hashes}};
+
+use crate::{errors::EditionsError, get_slot_hash, EditionsDeployment, URI_LIMIT};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct RevealInput {
+    // must hash to the provenance hash committed at initialise
+    pub item_base_uri: String,
+}
+
+#[derive(Accounts)]
+#[instruction(input: RevealInput)]
+pub struct RevealCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(
+        constraint = signer.key() == editions_deployment.creator)]
+    pub signer: Signer<'info>,
+}
+
+/*
+    Swaps the placeholder uri for the committed one and commits to the current slot, whose hash
+    nobody knows yet. The reveal is finished by draw_reveal_offset, which anybody can send, so the
+    creator cannot pick which mint gets which item. Mints are paused until then.
+*/
+pub fn reveal(ctx: Context<RevealCtx>, input: RevealInput) -> Result<()> {
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+
+    if !editions_deployment.has_delayed_reveal() {
+        return Err(EditionsError::NoDelayedReveal.into());
+    }
+    if editions_deployment.is_revealed || editions_deployment.reveal_slot > 0 {
+        return Err(EditionsError::AlreadyRevealed.into());
+    }
+    if input.item_base_uri.len() > URI_LIMIT {
+        return Err(EditionsError::UriTooLong.into());
+    }
+    if hash(input.item_base_uri.as_bytes()).to_bytes() != editions_deployment.provenance_hash {
+        return Err(EditionsError::ProvenanceHashMismatch.into());
+    }
+
+    let item_uri_is_template = match input.item_base_uri.matches("{}").count() {
+        0 => false,
+        1 => true,
+        _ => return Err(EditionsError::TooManyTemplates.into()),
+    };
+
+    editions_deployment.item_base_uri = input.item_base_uri;
+    editions_deployment.item_uri_is_template = item_uri_is_template;
+    editions_deployment.reveal_slot = Clock::get()?.slot;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DrawRevealOffsetCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    /// CHECK: address checked
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
+
+/*
+    Permissionless: finishes a reveal by drawing the offset from the hash of the slot reveal was
+    sent in. The slot hashes sysvar only keeps 512 slots, once the reveal slot has left it the
+    reveal is committed to the current slot instead, and a later draw_reveal_offset finishes it.
+*/
+pub fn draw_reveal_offset(ctx: Context<DrawRevealOffsetCtx>) -> Result<()> {
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+
+    if editions_deployment.is_revealed {
+        return Err(EditionsError::AlreadyRevealed.into());
+    }
+    if editions_deployment.reveal_slot == 0 {
+        return Err(EditionsError::NotRevealed.into());
+    }
+
+    let current_slot = Clock::get()?.slot;
+    if current_slot <= editions_deployment.reveal_slot {
+        return Err(EditionsError::RevealNotReady.into());
+    }
+
+    let Some(slot_hash) = get_slot_hash(&ctx.accounts.slot_hashes.to_account_info(), editions_deployment.reveal_slot)? else {
+        editions_deployment.reveal_slot = current_slot;
+        return Ok(());
+    };
+    let seed = u64::from_le_bytes(slot_hash[..8].try_into().unwrap());
+
+    editions_deployment.reveal_offset = match editions_deployment.max_number_of_tokens {
+        0 => 0,
+        max => (seed % max) as u32,
+    };
+    editions_deployment.is_revealed = true;
+
+    Ok(())
+}
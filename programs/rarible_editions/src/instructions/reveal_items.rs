@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_2022_extensions::{token_metadata_update_field, TokenMetadataUpdateField},
+};
+use spl_token_metadata_interface::state::Field;
+
+use crate::{errors::EditionsError, locate_hashlist_entry, utils::update_account_lamports_to_minimum_balance, EditionsDeployment, BURNED_ORDER_FLAG, PENDING_DRAW_FLAG};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct RevealItemsInput {
+    // hashlist position of every mint passed in the remaining accounts, in the same order
+    pub hashlist_indices: Vec<u32>,
+}
+
+#[derive(Accounts)]
+#[instruction(input: RevealItemsInput)]
+pub struct RevealItemsCtx<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    /// CHECK: Checked in PDA. Not deserialized because it can be rather big
+    #[account(
+        seeds = ["hashlist".as_bytes(), 
+        editions_deployment.key().as_ref()],
+        bump,)]
+    pub hashlist: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in locate_hashlist_entry, only required for mints recorded in a hashlist page
+    pub hashlist_page: Option<UncheckedAccount<'info>>,
+
+    // tops up mint rent when the uri grows
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Checked in constraint
+    #[account(
+        constraint = token_program.key() == token_2022::ID
+    )]
+    pub token_program: UncheckedAccount<'info>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+}
+
+/*
+    Permissionless. Rewrites the uri of already minted items to the revealed template.
+    Mints are passed in the remaining accounts, each checked against its hashlist entry.
+    Paged mints must all come from the hashlist page passed in.
+*/
+pub fn reveal_items<'info>(ctx: Context<'_, '_, '_, 'info, RevealItemsCtx<'info>>, input: RevealItemsInput) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    if !editions_deployment.is_revealed {
+        return Err(EditionsError::NotRevealed.into());
+    }
+    if input.hashlist_indices.len() != ctx.remaining_accounts.len() {
+        return Err(EditionsError::HashlistEntryMismatch.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    let hashlist_page = ctx.accounts.hashlist_page.as_ref().map(|x| x.to_account_info());
+    for (mint, hashlist_index) in ctx.remaining_accounts.iter().zip(input.hashlist_indices.iter()) {
+        let order = {
+            let (hashlist_account_info, mint_start_pos) = locate_hashlist_entry(
+                &editions_deployment.key(),
+                &ctx.accounts.hashlist.to_account_info(),
+                hashlist_page.as_ref(),
+                *hashlist_index,
+            )?;
+            let hashlist_data = hashlist_account_info.try_borrow_data()?;
+            if hashlist_data[mint_start_pos..mint_start_pos + 32] != mint.key().to_bytes() {
+                return Err(EditionsError::HashlistEntryMismatch.into());
+            }
+            u64::from_le_bytes(hashlist_data[mint_start_pos + 32..mint_start_pos + 40].try_into().unwrap())
+        };
+
+        // burned items keep their last uri, items still waiting for their number get theirs in draw_numbers
+        if order & (BURNED_ORDER_FLAG | PENDING_DRAW_FLAG) != 0 {
+            continue;
+        }
+
+        let item_url = editions_deployment.item_uri(Some(order));
+
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint.clone(),
+                    update_authority: editions_deployment.to_account_info(),
+                },
+                &[deployment_seeds],
+            ),
+            Field::Uri,
+            item_url,
+        )?;
+
+        update_account_lamports_to_minimum_balance(
+            mint.clone(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022,
+    token_interface::{self, Burn, CloseAccount, Mint, TokenAccount},
+};
+
+use crate::{errors::EditionsError, mark_hashlist_entry_burned, thaw_if_frozen, BurnInput, EditionsDeployment, HashlistMarker};
+
+#[derive(Accounts)]
+#[instruction(input: BurnInput)]
+pub struct RevokeCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    /// CHECK: Checked in PDA. Not deserialized because it can be rather big
+    #[account(mut,
+        seeds = ["hashlist".as_bytes(),
+        editions_deployment.key().as_ref()],
+        bump,)]
+    pub hashlist: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in locate_hashlist_entry, only required for mints recorded in a hashlist page
+    #[account(mut)]
+    pub hashlist_page: Option<UncheckedAccount<'info>>,
+
+    // rent goes back to the holder
+    #[account(mut,
+        close = holder,
+        seeds = ["hashlist_marker".as_bytes(),
+        editions_deployment.key().as_ref(),
+        mint.key().as_ref()],
+        bump,)]
+    pub hashlist_marker: Account<'info, HashlistMarker>,
+
+    #[account(
+        constraint = creator.key() == editions_deployment.creator
+    )]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Checked in constraint against the owner of the token account
+    #[account(mut,
+        constraint = token_account.owner == holder.key())]
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut,
+        token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /* BOILERPLATE PROGRAM ACCOUNTS */
+    /// CHECK: Checked in constraint
+    #[account(
+        constraint = token_program.key() == token_2022::ID
+    )]
+    pub token_program: UncheckedAccount<'info>,
+}
+
+/*
+    Burns an edition of a revocable deployment from whoever holds it, signed by the deployment
+    as permanent delegate. The token account stays open since only its owner can close it.
+*/
+pub fn revoke(ctx: Context<RevokeCtx>, input: BurnInput) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+    let mint = &ctx.accounts.mint;
+    let token_account = &ctx.accounts.token_account;
+
+    if !editions_deployment.revocable {
+        return Err(EditionsError::NotRevocable.into());
+    }
+    if token_account.amount == 0 {
+        return Err(EditionsError::NothingToBurn.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    // the permanent delegate can't burn from a frozen account either
+    thaw_if_frozen(
+        &token_account.to_account_info(),
+        &mint.to_account_info(),
+        &editions_deployment.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        deployment_seeds,
+    )?;
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: token_account.to_account_info(),
+                authority: editions_deployment.to_account_info(),
+            },
+            &[deployment_seeds],
+        ),
+        token_account.amount,
+    )?;
+
+    // mints with a close authority are closed as well, the edition is gone for good
+    if editions_deployment.mint_close_authority {
+        token_interface::close_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: mint.to_account_info(),
+                    destination: ctx.accounts.holder.to_account_info(),
+                    authority: editions_deployment.to_account_info(),
+                },
+                &[deployment_seeds],
+            ),
+        )?;
+    }
+
+    let hashlist_page = ctx.accounts.hashlist_page.as_ref().map(|x| x.to_account_info());
+    mark_hashlist_entry_burned(
+        &editions_deployment.key(),
+        &ctx.accounts.hashlist.to_account_info(),
+        hashlist_page.as_ref(),
+        input.hashlist_index,
+        &mint.key(),
+    )?;
+
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+    editions_deployment.number_of_tokens_burned += 1;
+    msg!(
+        "revoked {} from {}, supply {} of {} issued",
+        mint.key(),
+        ctx.accounts.holder.key(),
+        editions_deployment.current_supply(),
+        editions_deployment.number_of_tokens_issued
+    );
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::MetadataErrors;
+use crate::{RoyaltyConfig, RoyaltyCreator, MAX_CREATORS};
+
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct CreatorWithShare {
     pub address: Pubkey,
@@ -12,8 +15,49 @@ pub struct UpdateRoyaltiesArgs {
     pub creators: Vec<CreatorWithShare>,
 }
 
+impl RoyaltyConfig {
+    // creators that stay on the list keep their verification, new ones start unverified
+    pub fn set_royalties(&mut self, args: &UpdateRoyaltiesArgs) -> Result<()> {
+        require!(
+            args.royalty_basis_points <= 10000,
+            MetadataErrors::RoyaltyBasisPointsInvalid
+        );
+        require!(args.creators.len() <= MAX_CREATORS, MetadataErrors::TooManyCreators);
+
+        let mut total_share: u8 = 0;
+        let mut creators: Vec<RoyaltyCreator> = vec![];
+        for creator in args.creators.iter() {
+            total_share = total_share
+                .checked_add(creator.share)
+                .ok_or(MetadataErrors::CreatorShareInvalid)?;
+            if creators.iter().any(|c| c.address == creator.address) {
+                return Err(MetadataErrors::CreatorAddressInvalid.into());
+            }
+            creators.push(RoyaltyCreator {
+                address: creator.address,
+                share: creator.share,
+                verified: self.creators.iter().any(|c| c.address == creator.address && c.verified),
+            });
+        }
+
+        if total_share != 100 {
+            return Err(MetadataErrors::CreatorShareInvalid.into());
+        }
+
+        self.royalty_basis_points = args.royalty_basis_points;
+        self.creators = creators;
+        Ok(())
+    }
+}
+
 pub mod add;
 pub mod modify;
+pub mod pay;
+pub mod sync;
+pub mod verify;
 
 pub use add::*;
 pub use modify::*;
+pub use pay::*;
+pub use sync::*;
+pub use verify::*;
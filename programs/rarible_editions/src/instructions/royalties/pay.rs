@@ -0,0 +1,174 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::MetadataErrors;
+use crate::{ApproveAccount, EditionsDeployment, HashlistMarker, RoyaltyConfig, APPROVE_ACCOUNT_SEED};
+
+// number of slots (roughly a minute) the transfer has to happen in once royalties are paid
+pub const APPROVAL_SLOTS: u64 = 150;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct PayRoyaltiesArgs {
+    // sale price, in lamports or in the smallest unit of the payment mint
+    pub price: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: PayRoyaltiesArgs)]
+pub struct PayRoyalties<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    // proves that the mint belongs to the deployment
+    #[account(
+        seeds = ["hashlist_marker".as_bytes(),
+        editions_deployment.key().as_ref(),
+        mint.key().as_ref()],
+        bump,)]
+    pub hashlist_marker: Account<'info, HashlistMarker>,
+
+    #[account(
+        seeds = ["royalty_config".as_bytes(), editions_deployment.key().as_ref()],
+        bump)]
+    pub royalty_config: Box<Account<'info, RoyaltyConfig>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // token account the edition is sold out of, the approval only covers a transfer from it
+    #[account(token::mint = mint)]
+    pub seller_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(init_if_needed,
+        payer = payer,
+        space = ApproveAccount::SIZE,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump)]
+    pub approve_account: Account<'info, ApproveAccount>,
+
+    /* SPL PAYMENT ACCOUNTS - omit all of them to pay in SOL */
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(mut,
+        token::authority = payer)]
+    pub payer_payment_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+    Splits the royalty on a sale price between the creators in the royalty config and approves
+    the next transfer of the mint out of the seller token account for APPROVAL_SLOTS slots.
+    Creators are passed in the remaining accounts in config order: their wallets for SOL, their
+    payment token accounts otherwise.
+*/
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, PayRoyalties<'info>>, args: PayRoyaltiesArgs) -> Result<()> {
+    let royalty_config = &ctx.accounts.royalty_config;
+    if royalty_config.creators.is_empty() {
+        return Err(MetadataErrors::RoyaltiesNotSet.into());
+    }
+    let creators: Vec<(Pubkey, u64)> = royalty_config
+        .creators
+        .iter()
+        .map(|creator| (creator.address, creator.share as u64))
+        .collect();
+
+    if ctx.remaining_accounts.len() != creators.len() {
+        return Err(MetadataErrors::MissingCreatorAccount.into());
+    }
+    if args.price == 0 {
+        return Err(MetadataErrors::ZeroPrice.into());
+    }
+
+    let royalty = (args.price as u128)
+        .checked_mul(royalty_config.royalty_basis_points as u128)
+        .ok_or(MetadataErrors::RoyaltyBasisPointsInvalid)?
+        / 10000;
+    // an approval has to cost something, otherwise royalties could be skipped with a tiny price
+    if royalty == 0 {
+        return Err(MetadataErrors::ZeroRoyalty.into());
+    }
+
+    // rounding dust goes to the first creator
+    let mut amounts: Vec<u64> = creators
+        .iter()
+        .map(|(_, share)| (royalty * *share as u128 / 100) as u64)
+        .collect();
+    let dust = (royalty as u64).saturating_sub(amounts.iter().sum::<u64>());
+    if let Some(first) = amounts.first_mut() {
+        *first += dust;
+    }
+
+    for (((creator, _), creator_account), amount) in creators.iter().zip(ctx.remaining_accounts.iter()).zip(amounts) {
+        if amount == 0 {
+            continue;
+        }
+
+        match (
+            ctx.accounts.payment_mint.as_ref(),
+            ctx.accounts.payer_payment_account.as_ref(),
+            ctx.accounts.payment_token_program.as_ref(),
+        ) {
+            (Some(payment_mint), Some(payer_payment_account), Some(payment_token_program)) => {
+                let creator_token_account = {
+                    if creator_account.owner != &payment_token_program.key() {
+                        return Err(MetadataErrors::MissingCreatorAccount.into());
+                    }
+                    TokenAccount::try_deserialize(&mut &creator_account.try_borrow_data()?[..])?
+                };
+                if creator_token_account.owner != *creator || creator_token_account.mint != payment_mint.key() {
+                    return Err(MetadataErrors::MissingCreatorAccount.into());
+                }
+
+                transfer_checked(
+                    CpiContext::new(
+                        payment_token_program.to_account_info(),
+                        TransferChecked {
+                            from: payer_payment_account.to_account_info(),
+                            mint: payment_mint.to_account_info(),
+                            to: creator_account.clone(),
+                            authority: ctx.accounts.payer.to_account_info(),
+                        },
+                    ),
+                    amount,
+                    payment_mint.decimals,
+                )?;
+            },
+            (None, None, None) => {
+                if creator_account.key() != *creator {
+                    return Err(MetadataErrors::MissingCreatorAccount.into());
+                }
+
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: creator_account.clone(),
+                        },
+                    ),
+                    amount,
+                )?;
+            },
+            _ => return Err(MetadataErrors::MissingPaymentAccounts.into()),
+        }
+    }
+
+    let mint = ctx.accounts.mint.key();
+    let seller_token_account = &ctx.accounts.seller_token_account;
+    ctx.accounts.approve_account.set_inner(ApproveAccount {
+        mint,
+        token_account: seller_token_account.key(),
+        owner: seller_token_account.owner,
+        expiry_slot: Clock::get()?.slot + APPROVAL_SLOTS,
+    });
+
+    Ok(())
+}
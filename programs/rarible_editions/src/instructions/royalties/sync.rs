@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+
+use crate::errors::MetadataErrors;
+use crate::{EditionsDeployment, HashlistMarker, RoyaltyConfig};
+use crate::logic::write_royalty_metadata;
+use crate::utils::update_account_lamports_to_minimum_balance;
+
+#[derive(Accounts)]
+pub struct SyncRoyalties<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(
+        seeds = ["royalty_config".as_bytes(), editions_deployment.key().as_ref()],
+        bump)]
+    pub royalty_config: Box<Account<'info, RoyaltyConfig>>,
+
+    // proves that the mint belongs to the deployment, not needed for the group mint
+    #[account(
+        seeds = ["hashlist_marker".as_bytes(),
+        editions_deployment.key().as_ref(),
+        mint.key().as_ref()],
+        bump,)]
+    pub hashlist_marker: Option<Account<'info, HashlistMarker>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+/*
+    Permissionless: copies the royalty config of a deployment into the metadata of one of its mints.
+    This is also the migration path for mints written with creator fields keyed by address.
+*/
+pub fn handler(ctx: Context<SyncRoyalties>) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+    if ctx.accounts.hashlist_marker.is_none() && ctx.accounts.mint.key() != editions_deployment.group_mint {
+        return Err(MetadataErrors::MintNotInDeployment.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+    write_royalty_metadata(
+        &ctx.accounts.mint.to_account_info(),
+        &editions_deployment.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        deployment_seeds,
+        &ctx.accounts.royalty_config,
+    )?;
+
+    // transfer minimum rent to mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Token2022;
+
+use crate::errors::MetadataErrors;
+use crate::logic::write_royalty_metadata;
+use crate::utils::update_account_lamports_to_minimum_balance;
+use crate::{CreatorVerified, EditionsDeployment, RoyaltyConfig};
+
+#[derive(Accounts)]
+pub struct VerifyCreator<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(mut,
+        seeds = ["royalty_config".as_bytes(), editions_deployment.key().as_ref()],
+        bump)]
+    pub royalty_config: Box<Account<'info, RoyaltyConfig>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // one of the creators listed in the royalty config
+    pub creator: Signer<'info>,
+
+    /// CHECK: Checked in constraint
+    #[account(mut,
+        constraint = editions_deployment.group_mint == group_mint.key())]
+    pub group_mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn verify_creator(ctx: Context<VerifyCreator>) -> Result<()> {
+    set_creator_verified(ctx, true)
+}
+
+pub fn unverify_creator(ctx: Context<VerifyCreator>) -> Result<()> {
+    set_creator_verified(ctx, false)
+}
+
+/*
+    Only the creator itself can change its verified flag. The flag is written to the group mint
+    right away, minted editions pick it up with sync_royalties.
+*/
+fn set_creator_verified(ctx: Context<VerifyCreator>, verified: bool) -> Result<()> {
+    let creator_key = ctx.accounts.creator.key();
+    let royalty_config = &mut ctx.accounts.royalty_config;
+
+    let creator = royalty_config
+        .creators
+        .iter_mut()
+        .find(|creator| creator.address == creator_key)
+        .ok_or(MetadataErrors::CreatorAddressInvalid)?;
+    creator.verified = verified;
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        ctx.accounts.editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+    write_royalty_metadata(
+        &ctx.accounts.group_mint.to_account_info(),
+        &ctx.accounts.editions_deployment.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        deployment_seeds,
+        royalty_config,
+    )?;
+
+    // transfer minimum rent to group mint account
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.group_mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(CreatorVerified {
+        editions_deployment: ctx.accounts.editions_deployment.key(),
+        creator: creator_key,
+        verified,
+    });
+
+    Ok(())
+}
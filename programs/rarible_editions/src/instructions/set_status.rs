@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::EditionsError, DeploymentStatus, EditionsDeployment};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SetDeploymentStatusInput {
+    pub status: DeploymentStatus,
+}
+
+#[derive(Accounts)]
+#[instruction(input: SetDeploymentStatusInput)]
+pub struct SetDeploymentStatusCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(
+        constraint = signer.key() == editions_deployment.creator
+    )]
+    pub signer: Signer<'info>,
+}
+
+/*
+    Draft -> Live, Live <-> Paused, back to Draft while nothing has been minted,
+    and Closed from anywhere. Closed is final and MintedOut is only ever set by mint, finalize
+    and update_max_supply.
+*/
+pub fn set_deployment_status(ctx: Context<SetDeploymentStatusCtx>, input: SetDeploymentStatusInput) -> Result<()> {
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+
+    let allowed = match (editions_deployment.status, input.status) {
+        (DeploymentStatus::Closed, _) => false,
+        (_, DeploymentStatus::Closed) => true,
+        (DeploymentStatus::MintedOut, _) | (_, DeploymentStatus::MintedOut) => false,
+        (_, DeploymentStatus::Draft) => editions_deployment.number_of_tokens_issued == 0,
+        (_, DeploymentStatus::Live) | (_, DeploymentStatus::Paused) => true,
+    };
+
+    if !allowed || editions_deployment.status == input.status {
+        return Err(EditionsError::InvalidStatusTransition.into());
+    }
+
+    editions_deployment.status = input.status;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022;
+
+use crate::{errors::EditionsError, thaw_edition_accounts, EditionsDeployment};
+
+#[derive(Accounts)]
+pub struct ThawEditionsCtx<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(
+        constraint = creator.key() == editions_deployment.creator
+    )]
+    pub creator: Signer<'info>,
+
+    /* BOILERPLATE PROGRAM ACCOUNTS */
+    /// CHECK: Checked in constraint
+    #[account(
+        constraint = token_program.key() == token_2022::ID
+    )]
+    pub token_program: UncheckedAccount<'info>,
+}
+
+// token accounts and their mints are passed as pairs in the remaining accounts
+pub fn thaw_editions<'info>(ctx: Context<'_, '_, '_, 'info, ThawEditionsCtx<'info>>) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    thaw_edition_accounts(
+        ctx.remaining_accounts,
+        &editions_deployment.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        deployment_seeds,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ThawCtx<'info> {
+    #[account(
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    /* BOILERPLATE PROGRAM ACCOUNTS */
+    /// CHECK: Checked in constraint
+    #[account(
+        constraint = token_program.key() == token_2022::ID
+    )]
+    pub token_program: UncheckedAccount<'info>,
+}
+
+/*
+    Permissionless: thaws editions frozen by freeze_until_mint_out once the deployment has minted
+    out or its unlock time has passed. Deployments with frozen default account state cannot tell
+    those editions apart from accounts the creator has yet to approve, so only thaw_editions works there.
+*/
+pub fn thaw<'info>(ctx: Context<'_, '_, '_, 'info, ThawCtx<'info>>) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    if !editions_deployment.freeze_until_mint_out || editions_deployment.default_account_frozen {
+        return Err(EditionsError::CreatorThawOnly.into());
+    }
+    if !editions_deployment.is_thaw_unlocked(Clock::get()?.unix_timestamp) {
+        return Err(EditionsError::ThawLocked.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    thaw_edition_accounts(
+        ctx.remaining_accounts,
+        &editions_deployment.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        deployment_seeds,
+    )
+}
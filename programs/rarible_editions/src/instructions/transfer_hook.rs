@@ -0,0 +1,115 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as instructions_sysvar};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_token_2022::extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions};
+
+use crate::{errors::MetadataErrors, ApproveAccount, EditionsDeployment, RoyaltyConfig, APPROVE_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED};
+
+// accounts in the order of the transfer hook interface execute instruction
+#[derive(Accounts)]
+pub struct ExecuteTransferHookCtx<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: source token account owner or delegate, checked by the token program
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList, checked in PDA
+    #[account(
+        seeds = [META_LIST_ACCOUNT_SEED, mint.key().as_ref()],
+        bump)]
+    pub extra_metas_account: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in PDA, may not exist when royalties have not been paid
+    #[account(mut,
+        seeds = [APPROVE_ACCOUNT_SEED, mint.key().as_ref()],
+        bump)]
+    pub approve_account: UncheckedAccount<'info>,
+
+    /// CHECK: royalty config of the deployment, written to the extra account metas at mint.
+    /// may not exist when the deployment has no royalties
+    pub royalty_config: UncheckedAccount<'info>,
+
+    // written to the extra account metas at mint
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    /// CHECK: address checked
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/*
+    Invoked by token-2022 on every transfer of an edition minted with enforced royalties or
+    required transfer memos.
+
+    With required memos, the instruction before the one that transfers has to be a memo, like
+    the MemoTransfer token account extension asks of a transfer into the account.
+
+    With enforced royalties, once the deployment has royalties, the transfer only goes through
+    with an unexpired approval for the mint and the source token account, which is used up by
+    the transfer.
+*/
+pub fn execute_transfer_hook(ctx: Context<ExecuteTransferHookCtx>, _amount: u64) -> Result<()> {
+    // only the token program sets the transferring flag, so this cannot be called directly to use up an approval
+    {
+        let source_token_info = ctx.accounts.source_token.to_account_info();
+        let source_token_data = source_token_info.try_borrow_data()?;
+        let source_token_state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&source_token_data)?;
+        let transfer_hook_account = source_token_state.get_extension::<TransferHookAccount>()?;
+        if !bool::from(transfer_hook_account.transferring) {
+            return Err(MetadataErrors::NotTransferring.into());
+        }
+    }
+
+    if ctx.accounts.editions_deployment.require_transfer_memo {
+        check_previous_instruction_is_memo(&ctx.accounts.instructions_sysvar.to_account_info())?;
+    }
+    if !ctx.accounts.editions_deployment.enforce_royalties {
+        return Ok(());
+    }
+
+    // nothing to pay until royalties are set on the deployment
+    let royalty_config_info = ctx.accounts.royalty_config.to_account_info();
+    if royalty_config_info.owner != &crate::ID || royalty_config_info.data_is_empty() {
+        return Ok(());
+    }
+    let royalty_config = RoyaltyConfig::try_deserialize(&mut &royalty_config_info.try_borrow_data()?[..])?;
+    if royalty_config.creators.is_empty() || royalty_config.royalty_basis_points == 0 {
+        return Ok(());
+    }
+
+    let approve_account_info = ctx.accounts.approve_account.to_account_info();
+    if approve_account_info.owner != &crate::ID || approve_account_info.data_is_empty() {
+        return Err(MetadataErrors::MissingApproveAccount.into());
+    }
+
+    let mut approve_account = ApproveAccount::try_deserialize(&mut &approve_account_info.try_borrow_data()?[..])?;
+    // the approval is for the sale the royalties were paid on, not for any transfer of the mint
+    let source_token = &ctx.accounts.source_token;
+    if approve_account.mint != ctx.accounts.mint.key()
+        || approve_account.token_account != source_token.key()
+        || approve_account.owner != source_token.owner {
+        return Err(MetadataErrors::MissingApproveAccount.into());
+    }
+    if Clock::get()?.slot > approve_account.expiry_slot {
+        return Err(MetadataErrors::ExpiredApproveAccount.into());
+    }
+
+    approve_account.expiry_slot = 0;
+    approve_account.try_serialize(&mut &mut approve_account_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+fn check_previous_instruction_is_memo(instructions: &AccountInfo) -> Result<()> {
+    let previous_instruction = instructions_sysvar::get_instruction_relative(-1, instructions)
+        .map_err(|_| MetadataErrors::MissingTransferMemo)?;
+    if previous_instruction.program_id != spl_memo::id() && previous_instruction.program_id != spl_memo::v1::id() {
+        return Err(MetadataErrors::MissingTransferMemo.into());
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::{EditionsError, MetadataErrors},
+    group_extension_program, update_group_max_size, DeploymentStatus, EditionsDeployment,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct UpdateMaxSupplyInput {
+    // set to 0 for unlimited
+    pub max_number_of_tokens: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(input: UpdateMaxSupplyInput)]
+pub struct UpdateMaxSupplyCtx<'info> {
+    #[account(mut,
+        seeds = ["editions_deployment".as_ref(), editions_deployment.symbol.as_ref()], bump)]
+    pub editions_deployment: Account<'info, EditionsDeployment>,
+
+    #[account(
+        constraint = signer.key() == editions_deployment.creator
+    )]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Checked in constraint
+    #[account(mut,
+        constraint = editions_deployment.group == group.key())]
+    pub group: UncheckedAccount<'info>,
+
+    /// CHECK: address checked
+    #[account(address = group_extension_program::ID)]
+    pub group_extension_program: AccountInfo<'info>,
+}
+
+/*
+    Raises or lowers the max supply of the deployment and of its group, never below the number
+    of tokens already issued. A minted out deployment goes live again when the supply is raised,
+    and one lowered to what has been issued is minted out. Random numbering deployments keep their
+    supply, since the token indices are sized to it, and so do delayed reveal deployments, whose
+    provenance hash commits to a fixed set of items. Once the mint window has closed the supply is
+    left to finalize.
+*/
+pub fn update_max_supply(ctx: Context<UpdateMaxSupplyCtx>, input: UpdateMaxSupplyInput) -> Result<()> {
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    if editions_deployment.random_numbering || editions_deployment.has_delayed_reveal() {
+        return Err(EditionsError::MaxSupplyFixed.into());
+    }
+    if editions_deployment.mint_end_time > 0 && Clock::get()?.unix_timestamp > editions_deployment.mint_end_time {
+        return Err(EditionsError::MintWindowClosed.into());
+    }
+    if editions_deployment.status == DeploymentStatus::Closed {
+        return Err(EditionsError::InvalidStatusTransition.into());
+    }
+    if input.max_number_of_tokens > u32::MAX as u64 {
+        return Err(MetadataErrors::SizeExceedsMaxSize.into());
+    }
+    if input.max_number_of_tokens > 0 && input.max_number_of_tokens < editions_deployment.number_of_tokens_issued {
+        return Err(MetadataErrors::MaxSizeBelowCurrentSize.into());
+    }
+
+    let deployment_seeds: &[&[u8]] = &[
+        "editions_deployment".as_bytes(),
+        editions_deployment.symbol.as_ref(),
+        &[ctx.bumps.editions_deployment],
+    ];
+
+    // same mapping as initialise
+    update_group_max_size(
+        &ctx.accounts.group.to_account_info(),
+        &editions_deployment.to_account_info(),
+        &ctx.accounts.group_extension_program.to_account_info(),
+        deployment_seeds,
+        match input.max_number_of_tokens {
+            0 => u32::MAX,
+            max => max as u32,
+        },
+    )?;
+
+    let editions_deployment = &mut ctx.accounts.editions_deployment;
+    editions_deployment.max_number_of_tokens = input.max_number_of_tokens;
+
+    let is_minted_out = input.max_number_of_tokens > 0
+        && editions_deployment.number_of_tokens_issued >= input.max_number_of_tokens;
+    editions_deployment.status = match (editions_deployment.status, is_minted_out) {
+        (DeploymentStatus::MintedOut, false) => DeploymentStatus::Live,
+        (_, true) => DeploymentStatus::MintedOut,
+        (status, false) => status,
+    };
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ pub use instructions::*;
 declare_id!("E3NtU7ozVJ73VGQk4ZaYvKzZsFHQxQaeS7ijzcGg4Sd4");
 
 pub mod errors;
+pub mod events;
 pub mod state;
 
 pub mod logic;
@@ -15,6 +16,8 @@ pub use logic::*;
 
 pub use state::*;
 
+pub use events::*;
+
 pub mod group_extension_program {
     use anchor_lang::declare_id;
     declare_id!("5hx15GaPPqsYA61v6QpcGPpo125v7rfvEfZQ4dJErG5V");
@@ -37,6 +40,82 @@ pub mod rarible_editions {
         instructions::mint(ctx)
     }
 
+    /// raise or lower the max supply of a deployment and its group
+    pub fn update_max_supply(ctx: Context<UpdateMaxSupplyCtx>, input: UpdateMaxSupplyInput) -> Result<()> {
+        instructions::update_max_supply(ctx, input)
+    }
+
+    /// fix the supply of a deployment once its mint window has closed. permissionless
+    pub fn finalize(ctx: Context<FinalizeCtx>) -> Result<()> {
+        instructions::finalize(ctx)
+    }
+
+    /// burn an edition held by the signer
+    pub fn burn(ctx: Context<BurnCtx>, input: BurnInput) -> Result<()> {
+        instructions::burn(ctx, input)
+    }
+
+    /// burn an edition of a revocable deployment from any holder
+    pub fn revoke(ctx: Context<RevokeCtx>, input: BurnInput) -> Result<()> {
+        instructions::revoke(ctx, input)
+    }
+
+    /// thaw frozen token accounts of editions, see default_account_frozen
+    pub fn thaw_editions<'info>(ctx: Context<'_, '_, '_, 'info, ThawEditionsCtx<'info>>) -> Result<()> {
+        instructions::thaw_editions(ctx)
+    }
+
+    /// thaw editions frozen until mint-out once unlocked. permissionless
+    pub fn thaw<'info>(ctx: Context<'_, '_, '_, 'info, ThawCtx<'info>>) -> Result<()> {
+        instructions::thaw(ctx)
+    }
+
+    /// reveal the committed item uri of a delayed reveal deployment
+    pub fn reveal(ctx: Context<RevealCtx>, input: RevealInput) -> Result<()> {
+        instructions::reveal(ctx, input)
+    }
+
+    /// finish a reveal with an offset drawn from a slot after it. permissionless
+    pub fn draw_reveal_offset(ctx: Context<DrawRevealOffsetCtx>) -> Result<()> {
+        instructions::draw_reveal_offset(ctx)
+    }
+
+    /// update the uri of minted items after reveal. permissionless
+    pub fn reveal_items<'info>(ctx: Context<'_, '_, '_, 'info, RevealItemsCtx<'info>>, input: RevealItemsInput) -> Result<()> {
+        instructions::reveal_items(ctx, input)
+    }
+
+    /// draw the token numbers of random numbering mints from a slot after their mint. permissionless
+    pub fn draw_numbers<'info>(ctx: Context<'_, '_, '_, 'info, DrawNumbersCtx<'info>>, input: DrawNumbersInput) -> Result<()> {
+        instructions::draw_numbers(ctx, input)
+    }
+
+    /// move the deployment between draft, live, paused and closed
+    pub fn set_deployment_status(ctx: Context<SetDeploymentStatusCtx>, input: SetDeploymentStatusInput) -> Result<()> {
+        instructions::set_deployment_status(ctx, input)
+    }
+
+    /// propose a new creator for the deployment, who has to accept
+    pub fn propose_creator(ctx: Context<ProposeCreatorCtx>, input: ProposeCreatorInput) -> Result<()> {
+        instructions::propose_creator(ctx, input)
+    }
+
+    /// accept a pending creator proposal, signed by the new creator
+    pub fn accept_creator(ctx: Context<AcceptCreatorCtx>) -> Result<()> {
+        instructions::accept_creator(ctx)
+    }
+
+    /// give up creator rights for good. metadata and royalties can no longer be changed
+    pub fn renounce_creator(ctx: Context<RenounceCreatorCtx>) -> Result<()> {
+        instructions::renounce_creator(ctx)
+    }
+
+    /// called by token-2022 on transfers of editions with enforced royalties
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn execute_transfer_hook(ctx: Context<ExecuteTransferHookCtx>, amount: u64) -> Result<()> {
+        instructions::execute_transfer_hook(ctx, amount)
+    }
+
     /// add royalties to mint
     pub fn add_royalties(ctx: Context<AddRoyalties>, args: UpdateRoyaltiesArgs) -> Result<()> {
         royalties::add::handler(ctx, args)
@@ -50,11 +129,55 @@ pub mod rarible_editions {
         royalties::modify::handler(ctx, args)
     }
 
+    /// sign as one of the royalty creators to mark it verified
+    pub fn verify_creator(ctx: Context<VerifyCreator>) -> Result<()> {
+        royalties::verify::verify_creator(ctx)
+    }
+
+    /// clear the verified flag of a royalty creator, signed by that creator
+    pub fn unverify_creator(ctx: Context<VerifyCreator>) -> Result<()> {
+        royalties::verify::unverify_creator(ctx)
+    }
+
+    /// copy the royalties of a deployment into the metadata of one of its mints
+    pub fn sync_royalties(ctx: Context<SyncRoyalties>) -> Result<()> {
+        royalties::sync::handler(ctx)
+    }
+
+    /// pay royalties on a sale and approve the transfer of the mint
+    pub fn pay_royalties<'info>(
+        ctx: Context<'_, '_, '_, 'info, PayRoyalties<'info>>,
+        args: PayRoyaltiesArgs,
+    ) -> Result<()> {
+        royalties::pay::handler(ctx, args)
+    }
+
     /// add additional metadata to mint
     pub fn add_metadata(ctx: Context<AddMetadata>, args: Vec<AddMetadataArgs>) -> Result<()> {
         metadata::add::handler(ctx, args)
     }
 
+    /// update name, uri and additional metadata of a minted item
+    pub fn update_item_metadata(ctx: Context<UpdateItemMetadata>, args: UpdateItemMetadataArgs) -> Result<()> {
+        metadata::update_item::update_item_metadata(ctx, args)
+    }
+
+    /// update several minted items, passed in the remaining accounts
+    pub fn update_item_metadata_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateItemMetadataBatch<'info>>,
+        args: Vec<UpdateItemMetadataArgs>,
+    ) -> Result<()> {
+        metadata::update_item::update_item_metadata_batch(ctx, args)
+    }
+
+    /// update name, symbol and uri of the collection and the item templates
+    pub fn update_collection_metadata(
+        ctx: Context<UpdateCollectionMetadata>,
+        args: UpdateCollectionMetadataArgs,
+    ) -> Result<()> {
+        metadata::update_collection::update_collection_metadata(ctx, args)
+    }
+
     /// remove additional metadata to mint
     pub fn remove_metadata(
         ctx: Context<RemoveMetadata>,
@@ -0,0 +1,248 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    token_2022::{initialize_mint2, InitializeMint2},
+    token_2022_extensions::{
+        default_account_state_initialize, group_member_pointer_initialize, metadata_pointer_initialize,
+        mint_close_authority_initialize, non_transferable_mint_initialize, permanent_delegate_initialize,
+        token_member_initialize, token_metadata_initialize, transfer_hook_initialize,
+        DefaultAccountStateInitialize, GroupMemberPointerInitialize, MetadataPointerInitialize,
+        MintCloseAuthorityInitialize, NonTransferableMintInitialize, PermanentDelegateInitialize,
+        TokenMemberInitialize, TokenMetadataInitialize, TransferHookInitialize,
+    },
+};
+use spl_token_2022::{extension::ExtensionType, state::AccountState};
+use spl_token_group_interface::state::TokenGroupMember;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+/// Token-2022 extensions added to an edition mint on top of metadata and group membership.
+/// MemoTransfer is an extension of token accounts rather than mints, so required memos are
+/// checked by the transfer hook instead, see require_transfer_memo.
+pub struct EditionMintExtensions {
+    // program invoked on every transfer, None for no transfer hook
+    pub transfer_hook_program_id: Option<Pubkey>,
+    // soulbound editions, the holder can only burn them
+    pub non_transferable: bool,
+    // can transfer and burn the edition from any holder, None for no permanent delegate
+    pub permanent_delegate: Option<Pubkey>,
+    // can close the mint once its edition is burned, None for no close authority
+    pub close_authority: Option<Pubkey>,
+    // token accounts start out frozen until thawed by the freeze authority
+    pub default_account_frozen: bool,
+}
+
+impl EditionMintExtensions {
+    fn extension_types(&self) -> Vec<ExtensionType> {
+        let mut extension_types = vec![ExtensionType::MetadataPointer, ExtensionType::GroupMemberPointer];
+        if self.transfer_hook_program_id.is_some() {
+            extension_types.push(ExtensionType::TransferHook);
+        }
+        if self.non_transferable {
+            extension_types.push(ExtensionType::NonTransferable);
+        }
+        if self.permanent_delegate.is_some() {
+            extension_types.push(ExtensionType::PermanentDelegate);
+        }
+        if self.close_authority.is_some() {
+            extension_types.push(ExtensionType::MintCloseAuthority);
+        }
+        if self.default_account_frozen {
+            extension_types.push(ExtensionType::DefaultAccountState);
+        }
+        extension_types
+    }
+}
+
+pub struct EditionMintAccounts<'a, 'info> {
+    pub mint: &'a AccountInfo<'info>,
+    pub member: &'a AccountInfo<'info>,
+    pub group: &'a AccountInfo<'info>,
+    // mint authority, freeze authority and update authority of metadata and extensions
+    pub editions_deployment: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub group_extension_program: &'a AccountInfo<'info>,
+}
+
+/*
+    Creates an edition mint the same way create_token_2022_and_metadata does (metadata in the mint,
+    membership in the group extension program), with room for extensions that have to be
+    initialised before the mint itself, such as the transfer hook or non-transferability.
+*/
+pub fn create_edition_mint<'info>(
+    accounts: EditionMintAccounts<'_, 'info>,
+    token_metadata: TokenMetadata,
+    extensions: &EditionMintExtensions,
+    deployment_seeds: &[&[u8]],
+) -> Result<()> {
+    let authority = accounts.editions_deployment.key();
+    let token_program = accounts.token_program;
+    let rent = Rent::get()?;
+
+    // the token program reallocs the mint for the metadata, but the lamports have to be there up front
+    let mint_size = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions.extension_types())?;
+    let metadata_size = token_metadata.tlv_size_of()?;
+
+    system_program::create_account(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            system_program::CreateAccount {
+                from: accounts.payer.clone(),
+                to: accounts.mint.clone(),
+            },
+        ),
+        rent.minimum_balance(mint_size + metadata_size),
+        mint_size as u64,
+        &token_program.key(),
+    )?;
+
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(authority),
+        Some(accounts.mint.key()),
+    )?;
+
+    group_member_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            GroupMemberPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(authority),
+        Some(accounts.member.key()),
+    )?;
+
+    if let Some(transfer_hook_program_id) = extensions.transfer_hook_program_id {
+        transfer_hook_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                TransferHookInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: accounts.mint.clone(),
+                },
+            ),
+            Some(authority),
+            Some(transfer_hook_program_id),
+        )?;
+    }
+
+    if extensions.non_transferable {
+        non_transferable_mint_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                NonTransferableMintInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: accounts.mint.clone(),
+                },
+            ),
+        )?;
+    }
+
+    if let Some(permanent_delegate) = extensions.permanent_delegate {
+        permanent_delegate_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                PermanentDelegateInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: accounts.mint.clone(),
+                },
+            ),
+            &permanent_delegate,
+        )?;
+    }
+
+    if let Some(close_authority) = extensions.close_authority {
+        mint_close_authority_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MintCloseAuthorityInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: accounts.mint.clone(),
+                },
+            ),
+            Some(&close_authority),
+        )?;
+    }
+
+    if extensions.default_account_frozen {
+        default_account_state_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                DefaultAccountStateInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: accounts.mint.clone(),
+                },
+            ),
+            &AccountState::Frozen,
+        )?;
+    }
+
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: accounts.mint.clone(),
+            },
+        ),
+        0,
+        &authority,
+        Some(&authority),
+    )?;
+
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                token_program_id: token_program.clone(),
+                metadata: accounts.mint.clone(),
+                update_authority: accounts.editions_deployment.clone(),
+                mint_authority: accounts.editions_deployment.clone(),
+                mint: accounts.mint.clone(),
+            },
+            &[deployment_seeds],
+        ),
+        token_metadata.name,
+        token_metadata.symbol,
+        token_metadata.uri,
+    )?;
+
+    // single tlv entry: 8 byte discriminator, 4 byte length, then the member itself
+    let member_size = 8 + 4 + std::mem::size_of::<TokenGroupMember>();
+    system_program::create_account(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            system_program::CreateAccount {
+                from: accounts.payer.clone(),
+                to: accounts.member.clone(),
+            },
+        ),
+        rent.minimum_balance(member_size),
+        member_size as u64,
+        &accounts.group_extension_program.key(),
+    )?;
+
+    token_member_initialize(
+        CpiContext::new_with_signer(
+            accounts.group_extension_program.clone(),
+            TokenMemberInitialize {
+                token_program_id: accounts.group_extension_program.clone(),
+                member: accounts.member.clone(),
+                member_mint: accounts.mint.clone(),
+                member_mint_authority: accounts.editions_deployment.clone(),
+                group: accounts.group.clone(),
+                group_update_authority: accounts.editions_deployment.clone(),
+            },
+            &[deployment_seeds],
+        ),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{errors::EditionsError, utils::create_pda_account, Hashlist, MintAndOrder, BURNED_ORDER_FLAG};
+
+/*
+    Mints are recorded in fixed-size hashlist pages ["hashlist", deployment, page_index (u32 le)]
+    that fill one after another. Pages share the layout of the original hashlist account
+    (discriminator, deployment, u32 length, then 40 byte mint + order entries), so each page
+    deserializes as a Hashlist.
+
+    Deployments that minted before pages were introduced keep those entries in the original
+    ["hashlist", deployment] account, which no longer grows. Hashlist indices are global:
+    the entries of the original hashlist come first, then page 0, page 1, ...
+*/
+pub const HASHLIST_PAGE_SIZE: u32 = 1000;
+
+const HASHLIST_HEADER_SIZE: usize = 8 + 32 + 4;
+const HASHLIST_ENTRY_SIZE: usize = 32 + 8;
+
+pub fn get_hashlist_page_address(deployment: &Pubkey, page_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &["hashlist".as_bytes(), deployment.as_ref(), &page_index.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// number of entries stored in a hashlist or hashlist page
+pub fn get_hashlist_len(hashlist: &AccountInfo) -> Result<u32> {
+    let data = hashlist.try_borrow_data()?;
+    if data.len() < HASHLIST_HEADER_SIZE {
+        return Ok(0);
+    }
+    Ok(u32::from_le_bytes(data[40..44].try_into().unwrap()))
+}
+
+/// page and position within the page of the n-th (0-based) paged mint
+pub fn get_hashlist_page_position(paged_index: u64) -> (u32, u32) {
+    (
+        (paged_index / HASHLIST_PAGE_SIZE as u64) as u32,
+        (paged_index % HASHLIST_PAGE_SIZE as u64) as u32,
+    )
+}
+
+/// number of pages in use, for walking a deployment's hashlist in order
+pub fn get_hashlist_page_count(number_of_tokens_issued: u64, legacy_hashlist_len: u32) -> u32 {
+    let paged = number_of_tokens_issued.saturating_sub(legacy_hashlist_len as u64);
+    paged.div_ceil(HASHLIST_PAGE_SIZE as u64) as u32
+}
+
+/// decodes every entry of a hashlist or hashlist page
+pub fn read_hashlist_entries(data: &[u8]) -> Result<Vec<MintAndOrder>> {
+    let hashlist = Hashlist::try_deserialize(&mut &data[..])?;
+    Ok(hashlist.issues)
+}
+
+pub fn create_hashlist_page<'a>(
+    hashlist_page: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    deployment: &Pubkey,
+    page_index: u32,
+) -> Result<()> {
+    let (_, bump) = get_hashlist_page_address(deployment, page_index);
+    let page_index_bytes = page_index.to_le_bytes();
+
+    create_pda_account(
+        hashlist_page,
+        payer,
+        system_program,
+        HASHLIST_HEADER_SIZE,
+        &["hashlist".as_bytes(), deployment.as_ref(), &page_index_bytes, &[bump]],
+    )?;
+
+    let mut data = hashlist_page.try_borrow_mut_data()?;
+    data[0..8].copy_from_slice(&Hashlist::DISCRIMINATOR);
+    data[8..40].copy_from_slice(deployment.as_ref());
+    data[40..44].copy_from_slice(&0u32.to_le_bytes());
+
+    Ok(())
+}
+
+/// Returns the account holding the entry at `hashlist_index` (global, 0-based) together with the
+/// byte offset of the entry. `hashlist_page` is only needed for entries past the original hashlist.
+pub fn locate_hashlist_entry<'a>(
+    deployment: &Pubkey,
+    hashlist: &AccountInfo<'a>,
+    hashlist_page: Option<&AccountInfo<'a>>,
+    hashlist_index: u32,
+) -> Result<(AccountInfo<'a>, usize)> {
+    let legacy_len = get_hashlist_len(hashlist)?;
+
+    let (account, position) = if hashlist_index < legacy_len {
+        (hashlist.clone(), hashlist_index)
+    } else {
+        let (page_index, position) = get_hashlist_page_position((hashlist_index - legacy_len) as u64);
+        let hashlist_page = hashlist_page.ok_or(EditionsError::InvalidHashlistPage)?;
+        if hashlist_page.key() != get_hashlist_page_address(deployment, page_index).0 {
+            return Err(EditionsError::InvalidHashlistPage.into());
+        }
+        (hashlist_page.clone(), position)
+    };
+
+    if position >= get_hashlist_len(&account)? {
+        return Err(EditionsError::HashlistEntryMismatch.into());
+    }
+
+    Ok((account, HASHLIST_HEADER_SIZE + position as usize * HASHLIST_ENTRY_SIZE))
+}
+
+// flags the hashlist entry of a burned mint, keeping its position so that hashlist indices stay stable
+pub fn mark_hashlist_entry_burned<'a>(
+    deployment: &Pubkey,
+    hashlist: &AccountInfo<'a>,
+    hashlist_page: Option<&AccountInfo<'a>>,
+    hashlist_index: u32,
+    mint: &Pubkey,
+) -> Result<()> {
+    let (hashlist_account_info, mint_start_pos) = locate_hashlist_entry(deployment, hashlist, hashlist_page, hashlist_index)?;
+    let mut hashlist_data = hashlist_account_info.data.borrow_mut();
+    if hashlist_data[mint_start_pos..mint_start_pos + 32] != mint.to_bytes() {
+        return Err(EditionsError::HashlistEntryMismatch.into());
+    }
+
+    let order = u64::from_le_bytes(hashlist_data[mint_start_pos + 32..mint_start_pos + 40].try_into().unwrap());
+    hashlist_data[
+        mint_start_pos + 32..mint_start_pos + 40
+        ].copy_from_slice(&(order | BURNED_ORDER_FLAG).to_le_bytes());
+
+    Ok(())
+}
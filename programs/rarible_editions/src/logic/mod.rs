@@ -1,2 +1,20 @@
 pub mod add_to_hashlist;
-pub use add_to_hashlist::*;
\ No newline at end of file
+pub use add_to_hashlist::*;
+
+pub mod hashlist_pages;
+pub use hashlist_pages::*;
+
+pub mod token_indices;
+pub use token_indices::*;
+
+pub mod create_edition_mint;
+pub use create_edition_mint::*;
+
+pub mod royalty_metadata;
+pub use royalty_metadata::*;
+
+pub mod thaw;
+pub use thaw::*;
+
+pub mod update_group_max_size;
+pub use update_group_max_size::*;
@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint as BaseStateMint,
+    },
+    spl_token_metadata_interface::{
+        instruction::remove_key,
+        state::{Field, TokenMetadata},
+    },
+    token_metadata_update_field, TokenMetadataUpdateField,
+};
+
+use crate::{RoyaltyConfig, CREATOR_FIELD_PREFIX, ROYALTY_BASIS_POINTS_FIELD};
+
+// fields holding creators, including the legacy ones keyed by the creator address
+pub fn is_creator_field(field: &str) -> bool {
+    field.starts_with(CREATOR_FIELD_PREFIX) || Pubkey::from_str(field).is_ok()
+}
+
+/*
+    Brings the royalty fields of a mint in line with the royalty config: writes royalty_basis_points and
+    one creator:<index> field per creator, then removes creator fields left over from a longer creator list
+    as well as legacy fields keyed by creator address.
+*/
+pub fn write_royalty_metadata<'info>(
+    mint: &AccountInfo<'info>,
+    editions_deployment: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    deployment_seeds: &[&[u8]],
+    royalty_config: &RoyaltyConfig,
+) -> Result<()> {
+    let existing_fields: Vec<String> = {
+        let mint_data = mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<BaseStateMint>::unpack(&mint_data)?;
+        let metadata = mint_state.get_variable_len_extension::<TokenMetadata>()?;
+        metadata.additional_metadata.into_iter().map(|(key, _)| key).collect()
+    };
+
+    let mut fields = vec![(ROYALTY_BASIS_POINTS_FIELD.to_owned(), royalty_config.royalty_basis_points.to_string())];
+    for (index, creator) in royalty_config.creators.iter().enumerate() {
+        fields.push((RoyaltyConfig::creator_field(index), RoyaltyConfig::creator_value(creator)));
+    }
+
+    for (field, value) in fields.iter() {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    token_program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: editions_deployment.clone(),
+                },
+                &[deployment_seeds],
+            ),
+            Field::Key(field.clone()),
+            value.clone(),
+        )?;
+    }
+
+    for field in existing_fields {
+        if !is_creator_field(&field) || fields.iter().any(|(key, _)| *key == field) {
+            continue;
+        }
+        msg!("royalties::remove_field: {}", field);
+        invoke_signed(
+            &remove_key(
+                &token_program.key(),
+                &mint.key(),
+                &editions_deployment.key(),
+                field,
+                false,
+            ),
+            &[mint.clone(), editions_deployment.clone()],
+            &[deployment_seeds],
+        )?;
+    }
+
+    Ok(())
+}
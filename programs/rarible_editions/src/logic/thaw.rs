@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{thaw_account, ThawAccount};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    state::{Account as TokenAccountState, AccountState},
+};
+
+use crate::errors::EditionsError;
+
+/*
+    Thaws edition token accounts passed as (token account, mint) pairs. The deployment is freeze
+    authority of every edition mint, which the token program checks. Accounts that are not frozen
+    are skipped so that a batch does not fail on a holder that has already been thawed.
+*/
+// is_multiple_of is not available on the rustc of older platform tools
+#[allow(clippy::manual_is_multiple_of)]
+pub fn thaw_edition_accounts<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    editions_deployment: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    deployment_seeds: &[&[u8]],
+) -> Result<()> {
+    if remaining_accounts.len() % 2 != 0 {
+        return Err(EditionsError::MissingThawAccounts.into());
+    }
+
+    for accounts in remaining_accounts.chunks(2) {
+        thaw_if_frozen(&accounts[0], &accounts[1], editions_deployment, token_program, deployment_seeds)?;
+    }
+
+    Ok(())
+}
+
+// frozen token accounts can't be burned from, so burn and revoke thaw them first
+pub fn thaw_if_frozen<'info>(
+    token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    editions_deployment: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    deployment_seeds: &[&[u8]],
+) -> Result<()> {
+    let is_frozen = {
+        let token_account_data = token_account.try_borrow_data()?;
+        StateWithExtensions::<TokenAccountState>::unpack(&token_account_data)?.base.state == AccountState::Frozen
+    };
+    if !is_frozen {
+        return Ok(());
+    }
+
+    thaw_account(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            ThawAccount {
+                account: token_account.clone(),
+                mint: mint.clone(),
+                authority: editions_deployment.clone(),
+            },
+            &[deployment_seeds],
+        ),
+    )
+}
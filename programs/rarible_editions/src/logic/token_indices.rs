@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::EditionsError, utils::create_pda_account};
+
+// one bit per token, capped by the size of an account created from within a program
+pub const MAX_RANDOM_NUMBERING_TOKENS: u64 = 10_240 * 8;
+
+pub fn token_indices_size(max_number_of_tokens: u64) -> usize {
+    (max_number_of_tokens as usize).div_ceil(8)
+}
+
+pub fn create_token_indices<'a>(
+    token_indices: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    editions_deployment: &Pubkey,
+    max_number_of_tokens: u64,
+) -> Result<()> {
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[b"token_indices", editions_deployment.as_ref()],
+        &crate::ID,
+    );
+    if token_indices.key() != expected_key {
+        return Err(EditionsError::MissingTokenIndices.into());
+    }
+
+    create_pda_account(
+        token_indices,
+        payer,
+        system_program,
+        token_indices_size(max_number_of_tokens),
+        &[b"token_indices", editions_deployment.as_ref(), &[bump]],
+    )
+}
+
+/// Hash of `slot` in the slot hashes sysvar, which keeps the last 512 slots. None once `slot`
+/// has dropped out of the sysvar (or for a slot that has no hash yet).
+pub fn get_slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    // layout: number of entries (u64), then (slot u64, hash [u8; 32]) from the most recent
+    let data = slot_hashes.try_borrow_data()?;
+    let len = u64::from_le_bytes(data[0..8].try_into().unwrap()) as usize;
+
+    let entry_start = |index: usize| 8 + index * 40;
+    Ok((0..len)
+        .find(|index| data[entry_start(*index)..entry_start(*index) + 8] == slot.to_le_bytes())
+        .map(|index| data[entry_start(index) + 8..entry_start(index) + 40].try_into().unwrap()))
+}
+
+/// Picks an unused token number (1-based) and marks it as used. The (seed % free bits)-th free bit is taken.
+pub fn take_random_index(
+    token_indices: &AccountInfo,
+    seed: u64,
+    max_number_of_tokens: u64,
+) -> Result<u64> {
+    let mut data = token_indices.try_borrow_mut_data()?;
+
+    // bits past max_number_of_tokens are never set, so every free bit counted here is a valid number
+    let taken: u64 = data.iter().map(|byte| byte.count_ones() as u64).sum();
+    let remaining = max_number_of_tokens.saturating_sub(taken);
+    if remaining == 0 {
+        return Err(EditionsError::MintedOut.into());
+    }
+
+    let mut target = seed % remaining;
+    for (byte_index, byte) in data.iter_mut().enumerate() {
+        let free_bits = (!*byte).count_ones() as u64;
+        if target >= free_bits {
+            target -= free_bits;
+            continue;
+        }
+        for bit in 0..8 {
+            if *byte & (1 << bit) == 0 {
+                if target == 0 {
+                    *byte |= 1 << bit;
+                    return Ok((byte_index * 8 + bit) as u64 + 1);
+                }
+                target -= 1;
+            }
+        }
+    }
+
+    Err(EditionsError::MintedOut.into())
+}
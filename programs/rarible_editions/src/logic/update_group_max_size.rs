@@ -0,0 +1,24 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use spl_token_group_interface::instruction::update_group_max_size as update_group_max_size_ix;
+
+// sets the max size of the deployment's group in the group extension program, the deployment being its update authority
+pub fn update_group_max_size<'info>(
+    group: &AccountInfo<'info>,
+    editions_deployment: &AccountInfo<'info>,
+    group_extension_program: &AccountInfo<'info>,
+    deployment_seeds: &[&[u8]],
+    max_size: u32,
+) -> Result<()> {
+    invoke_signed(
+        &update_group_max_size_ix(
+            &group_extension_program.key(),
+            &group.key(),
+            &editions_deployment.key(),
+            max_size,
+        ),
+        &[group.clone(), editions_deployment.clone()],
+        &[deployment_seeds],
+    )?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use dyn_fmt::AsStrFormatExt;
 use solana_program::pubkey::Pubkey;
 
 pub const SYMBOL_LIMIT: usize = 100;
@@ -8,12 +9,18 @@ pub const META_LIST_ACCOUNT_SEED: &[u8] = b"extra-account-metas";
 pub const APPROVE_ACCOUNT_SEED: &[u8] = b"approve-account";
 pub const ROYALTY_BASIS_POINTS_FIELD: &str = "royalty_basis_points";
 pub const PLATFORM_FEE_VALUE: &str = "platform_fee_value";
+// creators are written to mint metadata as `creator:<index>` = `<address>:<share>:<verified 0|1>`
+pub const CREATOR_FIELD_PREFIX: &str = "creator:";
+pub const MAX_CREATORS: usize = 5;
 // Define constants for metadata keys
 
-#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+#[derive(Clone, Copy, AnchorDeserialize, AnchorSerialize, PartialEq, Eq, InitSpace)]
 pub enum DeploymentStatus {
-    Initialised,
-    MintedOut,
+    Live, // must stay first so that deployments created before statuses read as live
+    Draft, // not yet open for minting
+    Paused,
+    MintedOut, // set by mint once max_number_of_tokens is reached
+    Closed, // final, no more mints
 }
 
 #[account]
@@ -44,8 +51,126 @@ pub struct EditionsDeployment {
     pub item_name_is_template: bool,
 
     pub item_uri_is_template: bool,
+
+    // delayed reveal: sha256 of the revealed item_base_uri, all zeroes for no delayed reveal.
+    // until revealed, item_base_uri holds the placeholder uri
+    pub provenance_hash: [u8; 32],
+
+    // shifts the number used in revealed item uris, drawn by draw_reveal_offset
+    pub reveal_offset: u32,
+
+    pub is_revealed: bool,
+
+    // token numbers are drawn at random from the token_indices bitmap instead of sequentially
+    pub random_numbering: bool,
+
+    // editions burned by their holders. burned editions still count towards max_number_of_tokens
+    pub number_of_tokens_burned: u64,
+
+    // only live deployments can be minted from
+    pub status: DeploymentStatus,
+
+    // editions are minted with a transfer hook that only lets transfers through once royalties are paid
+    pub enforce_royalties: bool,
+
+    // editions are minted non-transferable
+    pub soulbound: bool,
+
+    // the deployment is permanent delegate of every edition, so that the creator can revoke them
+    pub revocable: bool,
+
+    // the deployment can close edition mints once burned, returning their rent to the holder
+    pub mint_close_authority: bool,
+
+    // token accounts of editions start out frozen, the creator thaws them with thaw_editions
+    pub default_account_frozen: bool,
+
+    // minters' token accounts are frozen until mint-out or unlock_time, then anybody can thaw them
+    pub freeze_until_mint_out: bool,
+
+    // unix timestamp after which editions can be thawed even if not minted out, 0 for none
+    pub unlock_time: i64,
+
+    // unix timestamps bounding the mint, 0 for no bound. supply is fixed by finalize once the window closes
+    pub mint_start_time: i64,
+    pub mint_end_time: i64,
+
+    // editions are minted with the transfer hook, which rejects transfers not preceded by a memo
+    pub require_transfer_memo: bool,
+
+    // random numbers are drawn in hashlist order, this is the hashlist index of the next one to draw
+    pub next_draw_index: u32,
+
+    // slot the reveal offset is drawn from, set by reveal. 0 until revealed
+    pub reveal_slot: u64,
     
-    pub padding: [u8; 98]
+    pub padding: [u8; 8]
+}
+
+impl EditionsDeployment {
+    pub fn has_delayed_reveal(&self) -> bool {
+        self.provenance_hash != [0; 32]
+    }
+
+    // between reveal and draw_reveal_offset item_base_uri already holds the revealed uri, but
+    // the offset of its numbers is not known yet
+    pub fn is_reveal_pending(&self) -> bool {
+        self.reveal_slot > 0 && !self.is_revealed
+    }
+
+    // number used in the item uri of the token minted as `number`
+    pub fn item_uri_number(&self, number: u64) -> u64 {
+        if !self.is_revealed || self.max_number_of_tokens == 0 {
+            return number;
+        }
+        (number - 1 + self.reveal_offset as u64) % self.max_number_of_tokens + 1
+    }
+
+    // name of the token minted as `number`. random numbers show as "?" until they are drawn
+    pub fn item_name(&self, number: Option<u64>) -> String {
+        match self.item_name_is_template {
+            true => self.item_base_name.format(&[number.map_or("?".to_string(), |number| number.to_string())]),
+            false => self.item_base_name.clone(),
+        }
+    }
+
+    // uri of the token minted as `number`. random numbers show as "?" until they are drawn
+    pub fn item_uri(&self, number: Option<u64>) -> String {
+        match self.item_uri_is_template {
+            true => self.item_base_uri.format(&[number.map_or("?".to_string(), |number| self.item_uri_number(number).to_string())]),
+            false => self.item_base_uri.clone(),
+        }
+    }
+
+    // edition mints need extensions, or a freeze authority, that create_token_2022_and_metadata cannot set up
+    pub fn has_mint_extensions(&self) -> bool {
+        self.enforce_royalties
+            || self.soulbound
+            || self.revocable
+            || self.mint_close_authority
+            || self.default_account_frozen
+            || self.freeze_until_mint_out
+            || self.require_transfer_memo
+    }
+
+    // the transfer hook enforces royalties and transfer memos
+    pub fn has_transfer_hook(&self) -> bool {
+        self.enforce_royalties || self.require_transfer_memo
+    }
+
+    // editions frozen by freeze_until_mint_out can be thawed. a closed deployment never mints out,
+    // so closing it unlocks its editions as well
+    pub fn is_thaw_unlocked(&self, now: i64) -> bool {
+        self.status == DeploymentStatus::MintedOut
+            || self.status == DeploymentStatus::Closed
+            || (self.max_number_of_tokens > 0 && self.number_of_tokens_issued >= self.max_number_of_tokens)
+            || (self.unlock_time > 0 && now >= self.unlock_time)
+    }
+
+    // editions in circulation. the TokenGroup size only ever grows, so it includes burned editions
+    pub fn current_supply(&self) -> u64 {
+        self.number_of_tokens_issued.saturating_sub(self.number_of_tokens_burned)
+    }
 }
 
 // slightly more extended 
@@ -59,12 +184,77 @@ impl HashlistMarker{
     pub const SIZE: usize = 8 + 32 + 32;
 }
 
+// set on MintAndOrder.order once the mint has been burned
+pub const BURNED_ORDER_FLAG: u64 = 1 << 63;
+
+// set on MintAndOrder.order of random numbering mints until draw_numbers assigns their number.
+// the remaining bits hold the slot the number is drawn from: the slot of the mint, or a later one
+// once the mint slot has left the slot hashes sysvar
+pub const PENDING_DRAW_FLAG: u64 = 1 << 62;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct MintAndOrder {
     pub mint: Pubkey,
     pub order: u64,
 }
 
+// lets the next transfer of a mint through the royalty transfer hook, [APPROVE_ACCOUNT_SEED, mint]
+#[account]
+pub struct ApproveAccount {
+    pub mint: Pubkey,
+    // the sale is approved out of this token account only, while it is still held by owner
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    // last slot the approval can be used in, reset to 0 once used
+    pub expiry_slot: u64,
+}
+
+impl ApproveAccount {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RoyaltyCreator {
+    pub address: Pubkey,
+    pub share: u8,
+    // set by the creator itself with verify_creator
+    pub verified: bool,
+}
+
+// royalties of a deployment, ["royalty_config", deployment]. mint metadata mirrors it
+#[account]
+#[derive(InitSpace)]
+pub struct RoyaltyConfig {
+    pub editions_deployment: Pubkey,
+    pub royalty_basis_points: u16,
+    #[max_len(MAX_CREATORS)]
+    pub creators: Vec<RoyaltyCreator>,
+    pub padding: [u8; 64],
+}
+
+impl RoyaltyConfig {
+    pub fn creator_field(index: usize) -> String {
+        format!("{}{}", CREATOR_FIELD_PREFIX, index)
+    }
+
+    pub fn creator_value(creator: &RoyaltyCreator) -> String {
+        format!("{}:{}:{}", creator.address, creator.share, creator.verified as u8)
+    }
+}
+
+// creator handoff waiting for the new creator to accept, ["pending_creator", deployment]
+#[account]
+pub struct PendingCreator {
+    pub editions_deployment: Pubkey,
+    // creator at the time of the proposal, the proposal lapses when the creator changes
+    pub creator: Pubkey,
+    pub pending_creator: Pubkey,
+}
+
+impl PendingCreator {
+    pub const SIZE: usize = 8 + 32 + 32 + 32;
+}
+
 // this is a genuine hashlist for the launch
 #[account]
 pub struct Hashlist {
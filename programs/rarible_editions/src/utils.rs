@@ -1,4 +1,4 @@
-use crate::{APPROVE_ACCOUNT_SEED};
+use crate::{APPROVE_ACCOUNT_SEED, META_LIST_ACCOUNT_SEED};
 use anchor_lang::{
     prelude::Result,
     solana_program::{
@@ -7,7 +7,7 @@ use anchor_lang::{
         pubkey::Pubkey,
         rent::Rent,
         system_instruction::transfer,
-        sysvar::Sysvar,
+        sysvar::{instructions as instructions_sysvar, Sysvar},
     },
     Lamports,
 };
@@ -20,7 +20,11 @@ use anchor_spl::token_interface::{
     },
     spl_token_metadata_interface::state::TokenMetadata,
 };
-use spl_tlv_account_resolution::{account::ExtraAccountMeta};
+use anchor_lang::{prelude::{CpiContext, Key}, system_program};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::errors::MetadataErrors;
 
 pub fn update_account_lamports_to_minimum_balance<'info>(
     account: AccountInfo<'info>,
@@ -39,9 +43,47 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     Ok(())
 }
 
-// pub fn get_approve_account_pda(mint: Pubkey) -> Pubkey {
-//     Pubkey::find_program_address(&[APPROVE_ACCOUNT_SEED, mint.as_ref()], &crate::id()).0
-// }
+/// Creates a PDA owned by this program. Anybody can send lamports to the address up front, which
+/// would make create_account fail, so the rent is topped up and the account allocated and assigned.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let lamports_diff = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.get_lamports());
+    if lamports_diff > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports_diff,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: account.clone() },
+            &[signer_seeds],
+        ),
+        &crate::id(),
+    )
+}
 
 pub fn get_mint_metadata(account: &mut AccountInfo) -> Result<TokenMetadata> {
     let mint_data = account.data.borrow();
@@ -57,15 +99,53 @@ pub fn get_extension_data<T: Extension + Pod>(account: &mut AccountInfo) -> Resu
     Ok(extension_data)
 }
 
-// pub fn get_meta_list(approve_account: Pubkey) -> Vec<ExtraAccountMeta> {
-//     vec![ExtraAccountMeta {
-//         discriminator: 0,
-//         address_config: approve_account.to_bytes(),
-//         is_signer: false.into(),
-//         is_writable: true.into(),
-//     }]
-// }
+// the approve account is derived from the mint (account 1 of the transfer hook execute instruction).
+// the deployment and its royalty config are not part of the transfer, so they are stored as is
+pub fn get_meta_list(editions_deployment: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    let (royalty_config, _) =
+        Pubkey::find_program_address(&["royalty_config".as_bytes(), editions_deployment.as_ref()], &crate::id());
+    Ok(vec![
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: APPROVE_ACCOUNT_SEED.to_vec() },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            true,
+        )?,
+        ExtraAccountMeta::new_with_pubkey(&royalty_config, false, false)?,
+        ExtraAccountMeta::new_with_pubkey(editions_deployment, false, false)?,
+        ExtraAccountMeta::new_with_pubkey(&instructions_sysvar::ID, false, false)?,
+    ])
+}
+
+pub fn get_meta_list_size() -> Result<usize> {
+    Ok(ExtraAccountMetaList::size_of(get_meta_list(&Pubkey::default())?.len())?)
+}
+
+pub fn create_extra_metas_account<'info>(
+    extra_metas_account: &AccountInfo<'info>,
+    mint: &Pubkey,
+    editions_deployment: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected_extra_metas_account, bump) =
+        Pubkey::find_program_address(&[META_LIST_ACCOUNT_SEED, mint.as_ref()], &crate::id());
+    if extra_metas_account.key() != expected_extra_metas_account {
+        return Err(MetadataErrors::MissingExtraMetasAccount.into());
+    }
 
-// pub fn get_meta_list_size(approve_account: Pubkey) -> usize {
-//     ExtraAccountMetaList::size_of(get_meta_list(approve_account).len()).unwrap()
-// }
+    create_pda_account(
+        extra_metas_account,
+        payer,
+        system_program,
+        get_meta_list_size()?,
+        &[META_LIST_ACCOUNT_SEED, mint.as_ref(), &[bump]],
+    )?;
+
+    let mut data = extra_metas_account.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &get_meta_list(editions_deployment)?)?;
+
+    Ok(())
+}
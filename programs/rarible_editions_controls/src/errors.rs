@@ -47,6 +47,9 @@ pub enum EditionsControlsError {
     #[msg("Recipient account does not match the expected address.")]
     RecipientMismatch,
 
+    #[msg("Platform fee recipient account missing from the remaining accounts.")]
+    MissingFeeRecipient,
+
     #[msg("No phases have been added. Cannot mint.")]
     NoPhasesAdded,
 
@@ -89,4 +92,82 @@ pub enum EditionsControlsError {
     #[msg("Exceeded wallet max mints for the collection")]
     ExceededWalletMaxMintsForCollection,
 
+    #[msg("Phase has been removed")]
+    PhaseRemoved,
+
+    #[msg("Phase end time must be after its start time")]
+    InvalidPhaseTimes,
+
+    #[msg("Max mints total cannot be lower than the current mints of the phase")]
+    MaxMintsBelowCurrentMints,
+
+    #[msg("Merkle root must be provided for private phases")]
+    MerkleRootRequiredForPrivatePhase,
+
+    #[msg("Payment token accounts are required for phases not priced in native SOL")]
+    MissingPaymentAccounts,
+
+    #[msg("Payment mint does not match the phase price token")]
+    PaymentMintMismatch,
+
+    #[msg("Payment token account does not match the expected owner or mint")]
+    PaymentAccountMismatch,
+
+    #[msg("Invalid dutch auction configuration")]
+    InvalidDutchAuction,
+
+    #[msg("Dutch auction rebates are only supported for phases priced in native SOL")]
+    RebateRequiresNativePrice,
+
+    #[msg("Rebates can only be claimed once the phase has ended or sold out")]
+    RebateNotAvailable,
+
+    #[msg("Nothing to claim")]
+    NoRebateToClaim,
+
+    #[msg("Quantity must be at least 1")]
+    InvalidQuantity,
+
+    #[msg("Mint, member, hashlist marker, token account, hashlist page and, with enforced royalties, extra account metas are required in the remaining accounts for every additional edition")]
+    MissingMintAccounts,
+
+    #[msg("Invalid token gate configuration")]
+    InvalidTokenGate,
+
+    #[msg("Token gate accounts are required for this phase")]
+    MissingGateAccounts,
+
+    #[msg("Minter does not hold the token required by this phase")]
+    TokenGateNotMet,
+
+    #[msg("This gating token has already been used in this phase")]
+    GateTokenAlreadyUsed,
+
+    #[msg("Single use token gates allow one edition per gating token")]
+    GateSingleUseQuantity,
+
+    #[msg("Minter must sign to burn the gating token")]
+    BurnRequiresMinterSignature,
+
+    #[msg("A co-signature is required to mint")]
+    CosignatureRequired,
+
+    #[msg("Invalid co-signature")]
+    InvalidCosignature,
+
+    #[msg("Co-signature has expired")]
+    CosignatureExpired,
+
+    #[msg("Co-signature nonce has already been used")]
+    CosignNonceReused,
+
+    #[msg("Signer is not the pending creator")]
+    NotPendingCreator,
+
+    #[msg("Creator changed since the proposal")]
+    StaleCreatorProposal,
+
+    #[msg("End time and max mints total of a rebate auction cannot change once it has ended or a rebate was claimed")]
+    RebatePhaseLocked,
+
 }
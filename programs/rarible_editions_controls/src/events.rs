@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CreatorProposed {
+    pub editions_controls: Pubkey,
+    pub creator: Pubkey,
+    pub pending_creator: Pubkey,
+}
+
+#[event]
+pub struct CreatorTransferred {
+    pub editions_controls: Pubkey,
+    pub previous_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct CreatorRenounced {
+    pub editions_controls: Pubkey,
+    pub previous_creator: Pubkey,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::EditionsControlsError, CreatorTransferred, EditionsControls, PendingCreator};
+
+#[derive(Accounts)]
+pub struct AcceptCreatorCtx<'info> {
+    #[account(mut)]
+    pub editions_controls: Account<'info, EditionsControls>,
+
+    // rent goes to the new creator
+    #[account(mut,
+        close = new_creator,
+        seeds = [b"pending_creator", editions_controls.key().as_ref()],
+        bump)]
+    pub pending_creator: Account<'info, PendingCreator>,
+
+    #[account(mut)]
+    pub new_creator: Signer<'info>,
+}
+
+pub fn accept_creator(ctx: Context<AcceptCreatorCtx>) -> Result<()> {
+    let pending_creator = &ctx.accounts.pending_creator;
+    let editions_controls = &mut ctx.accounts.editions_controls;
+
+    if pending_creator.pending_creator != ctx.accounts.new_creator.key() {
+        return Err(EditionsControlsError::NotPendingCreator.into());
+    }
+    if pending_creator.creator != editions_controls.creator {
+        return Err(EditionsControlsError::StaleCreatorProposal.into());
+    }
+
+    let previous_creator = editions_controls.creator;
+    editions_controls.creator = pending_creator.pending_creator;
+
+    emit!(CreatorTransferred {
+        editions_controls: editions_controls.key(),
+        previous_creator,
+        new_creator: editions_controls.creator,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,24 @@
 use anchor_lang::prelude::*;
-use rarible_editions::program::RaribleEditions;
 use libreplex_shared::wrapped_sol;
+use rarible_editions::program::RaribleEditions;
+
+use crate::{EditionsControls, GateType, Phase, PricingMode, errors::EditionsControlsError};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct DutchAuctionInput {
+    pub floor_price: u64, // price_amount is the start price
+    pub step_seconds: i64,
+    pub price_decrement: u64, // price drop per step
+    pub rebate: bool, // native SOL phases only
+}
 
-use crate::{EditionsControls, Phase};
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct TokenGateInput {
+    pub gate_type: GateType,
+    pub gate_key: Pubkey, // group for GroupMember, deployment for BurnEdition, mint otherwise
+    pub min_balance: u64, // TokenBalance and BurnToken only
+    pub single_use: bool, // GroupMember only
+}
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct InitialisePhaseInput {
@@ -14,6 +30,8 @@ pub struct InitialisePhaseInput {
     pub end_time: i64, 
     pub is_private: bool,
     pub merkle_root: Option<[u8; 32]>,
+    pub dutch_auction: Option<DutchAuctionInput>, // None for a fixed price phase
+    pub token_gate: Option<TokenGateInput>, // None for an ungated phase
 }
 
 #[derive(Accounts)]
@@ -45,17 +63,35 @@ pub struct AddPhaseCtx<'info> {
 }
 
 pub fn add_phase(ctx: Context<AddPhaseCtx>, input: InitialisePhaseInput) -> Result<()> {
-    if !input.price_token.eq(&wrapped_sol::ID) {
-        panic!("Only native price currently supported")
-    }
-
     if input.is_private && input.merkle_root.is_none() {
         panic!("Merkle root must be provided for private phases");
     }
 
+    if let Some(dutch_auction) = &input.dutch_auction {
+        // rebates are escrowed in lamports on the minter stats account
+        if dutch_auction.rebate && !input.price_token.eq(&wrapped_sol::ID) {
+            return Err(EditionsControlsError::RebateRequiresNativePrice.into());
+        }
+    }
+
+    if let Some(token_gate) = &input.token_gate {
+        let valid = match token_gate.gate_type {
+            GateType::None => false,
+            GateType::GroupMember => true,
+            // fungible tokens cannot be tracked one by one
+            GateType::TokenBalance => token_gate.min_balance > 0 && !token_gate.single_use,
+            // burned tokens cannot be used twice anyway
+            GateType::BurnEdition => !token_gate.single_use,
+            GateType::BurnToken => token_gate.min_balance > 0 && !token_gate.single_use,
+        };
+        if !valid {
+            return Err(EditionsControlsError::InvalidTokenGate.into());
+        }
+    }
+
     let editions_controls = &mut ctx.accounts.editions_controls;
 
-    editions_controls.phases.push(Phase{ 
+    let phase = Phase{ 
         price_amount: input.price_amount, 
         price_token: input.price_token,
         start_time: input.start_time, 
@@ -66,8 +102,25 @@ pub fn add_phase(ctx: Context<AddPhaseCtx>, input: InitialisePhaseInput) -> Resu
         current_mints: 0,
         is_private: input.is_private,
         merkle_root: input.merkle_root,
-        padding: [0; 200],
-    });
+        removed: false,
+        pricing_mode: if input.dutch_auction.is_some() { PricingMode::DutchAuction } else { PricingMode::Fixed },
+        floor_price: input.dutch_auction.as_ref().map_or(0, |d| d.floor_price),
+        step_seconds: input.dutch_auction.as_ref().map_or(0, |d| d.step_seconds),
+        price_decrement: input.dutch_auction.as_ref().map_or(0, |d| d.price_decrement),
+        rebate_enabled: input.dutch_auction.as_ref().is_some_and(|d| d.rebate),
+        clearing_price: 0,
+        gate_type: input.token_gate.as_ref().map_or(GateType::None, |g| g.gate_type),
+        gate_key: input.token_gate.as_ref().map_or(Pubkey::default(), |g| g.gate_key),
+        gate_min_balance: input.token_gate.as_ref().map_or(0, |g| g.min_balance),
+        gate_single_use: input.token_gate.as_ref().is_some_and(|g| g.single_use),
+        rebate_claimed: false,
+        padding: [0; 122],
+    };
+    if !phase.has_valid_dutch_auction() {
+        return Err(EditionsControlsError::InvalidDutchAuction.into());
+    }
+
+    editions_controls.phases.push(phase);
 
     Ok(())
 }
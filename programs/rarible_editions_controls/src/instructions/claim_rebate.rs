@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use rarible_editions::EditionsDeployment;
+
+use crate::{
+    EditionsControls,
+    MinterStats,
+    errors::EditionsControlsError,
+    get_rebate_amount,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ClaimRebateInput {
+    pub phase_index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(input: ClaimRebateInput)]
+pub struct ClaimRebateCtx<'info> {
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        mut,
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"minter_stats_phase",
+            editions_deployment.key().as_ref(),
+            minter.key().as_ref(),
+            &input.phase_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub minter_stats_phase: Box<Account<'info, MinterStats>>,
+
+    /// CHECK: receives the rebate, checked via the minter_stats_phase seeds
+    #[account(mut)]
+    pub minter: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in constraint
+    #[account(
+        mut,
+        constraint = editions_controls.treasury == treasury.key()
+    )]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+/*
+    Permissionless. Once the clearing price of a dutch auction phase is final (the phase has
+    ended, sold out or was removed), the escrowed proceeds of a minter are split: the
+    overpayment goes back to the minter and the rest goes to the treasury.
+*/
+pub fn claim_rebate(ctx: Context<ClaimRebateCtx>, input: ClaimRebateInput) -> Result<()> {
+    let editions_controls = &mut ctx.accounts.editions_controls;
+    let minter_stats_phase = &mut ctx.accounts.minter_stats_phase;
+
+    let phase = editions_controls.phases
+        .get_mut(input.phase_index as usize)
+        .ok_or(EditionsControlsError::InvalidPhaseIndex)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let sold_out = phase.max_mints_total > 0 && phase.current_mints >= phase.max_mints_total;
+    let deployment = &ctx.accounts.editions_deployment;
+    let collection_minted_out = deployment.max_number_of_tokens > 0
+        && deployment.number_of_tokens_issued >= deployment.max_number_of_tokens;
+    if phase.end_time > current_time && !sold_out && !collection_minted_out && !phase.removed {
+        return Err(EditionsControlsError::RebateNotAvailable.into());
+    }
+
+    let escrowed_amount = minter_stats_phase.escrowed_amount;
+    if escrowed_amount == 0 {
+        return Err(EditionsControlsError::NoRebateToClaim.into());
+    }
+
+    let rebate = get_rebate_amount(
+        phase,
+        minter_stats_phase.amount_paid,
+        minter_stats_phase.rebate_mints,
+        escrowed_amount,
+    )?;
+    let treasury_amount = escrowed_amount - rebate;
+
+    // the clearing price is final from here on, see update_phase
+    phase.rebate_claimed = true;
+
+    minter_stats_phase.amount_paid = 0;
+    minter_stats_phase.rebate_mints = 0;
+    minter_stats_phase.escrowed_amount = 0;
+
+    let minter_stats_phase_info = minter_stats_phase.to_account_info();
+    **minter_stats_phase_info.try_borrow_mut_lamports()? -= escrowed_amount;
+    **ctx.accounts.minter.to_account_info().try_borrow_mut_lamports()? += rebate;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += treasury_amount;
+
+    Ok(())
+}
@@ -18,7 +18,19 @@ pub struct InitialiseControlInput {
     pub extra_meta: Vec<AddMetadataArgs>,
     pub item_base_uri: String,
     pub item_base_name: String,
-    pub platform_fee: UpdatePlatformFeeArgs
+    pub platform_fee: UpdatePlatformFeeArgs,
+    pub provenance_hash: Option<[u8; 32]>, // sha256 of the final item_base_uri for a delayed reveal
+    pub random_numbering: bool, // draw token numbers at random, requires max_number_of_tokens
+    pub enforce_royalties: bool, // editions can only be transferred once royalties are paid
+    pub soulbound: bool, // editions are non-transferable
+    pub revocable: bool, // the creator can revoke editions from any holder
+    pub mint_close_authority: bool, // edition mints are closed when burned
+    pub default_account_frozen: bool, // token accounts start frozen until the creator thaws them
+    pub require_transfer_memo: bool, // transfers of editions must come right after a memo
+    pub freeze_until_mint_out: bool, // minted editions stay frozen until mint-out or unlock_time
+    pub unlock_time: Option<i64>,
+    pub mint_start_time: Option<i64>, // open editions: unix timestamps bounding the mint,
+    pub mint_end_time: Option<i64>,   // the supply is fixed by finalize once closed
 }
 
 #[derive(Accounts)]
@@ -40,6 +52,10 @@ pub struct InitialiseEditionControlsCtx<'info> {
     #[account(mut)]
     pub hashlist: UncheckedAccount<'info>,
 
+    /// CHECK: Created via CPI
+    #[account(mut)]
+    pub royalty_config: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -55,6 +71,10 @@ pub struct InitialiseEditionControlsCtx<'info> {
     #[account(mut)]
     pub group: Signer<'info>,
 
+    /// CHECK: Checked via CPI, only required for random numbering
+    #[account(mut)]
+    pub token_indices: Option<UncheckedAccount<'info>>,
+
     #[account()]
     pub system_program: Program<'info, System>,
 
@@ -92,7 +112,19 @@ pub fn initialise_editions_controls(
         collection_uri: input.collection_uri,
         creator_cosign_program_id: Some(crate::ID),
         item_base_name: input.item_base_name,
-        item_base_uri: input.item_base_uri
+        item_base_uri: input.item_base_uri,
+        provenance_hash: input.provenance_hash,
+        random_numbering: input.random_numbering,
+        enforce_royalties: input.enforce_royalties,
+        soulbound: input.soulbound,
+        revocable: input.revocable,
+        mint_close_authority: input.mint_close_authority,
+        default_account_frozen: input.default_account_frozen,
+        require_transfer_memo: input.require_transfer_memo,
+        freeze_until_mint_out: input.freeze_until_mint_out,
+        unlock_time: input.unlock_time,
+        mint_start_time: input.mint_start_time,
+        mint_end_time: input.mint_end_time,
     };
 
     // Initialize the editions using CPI
@@ -106,6 +138,7 @@ pub fn initialise_editions_controls(
                 creator: editions_controls.to_account_info(),
                 group: group.to_account_info(),
                 group_mint: group_mint.to_account_info(),
+                token_indices: ctx.accounts.token_indices.as_ref().map(|a| a.to_account_info()),
                 system_program: system_program.to_account_info(),
                 token_program: token_program.to_account_info(),
                 group_extension_program: group_extension_program.to_account_info(),
@@ -184,6 +217,7 @@ pub fn initialise_editions_controls(
             rarible_editions_program.to_account_info(),
             AddRoyalties {
                 editions_deployment: editions_deployment.to_account_info(),
+                royalty_config: ctx.accounts.royalty_config.to_account_info(),
                 payer: payer.to_account_info(),
                 system_program: system_program.to_account_info(),
                 token_program: token_program.to_account_info(),
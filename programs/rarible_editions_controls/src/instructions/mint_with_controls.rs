@@ -1,20 +1,32 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as instructions_sysvar, system_program};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_2022::{self, ID as TOKEN_2022_ID},
+    token_2022_extensions::{transfer_checked_with_fee, TransferCheckedWithFee},
+    token_interface::{self, transfer_checked, Burn, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
+use spl_token_2022::extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions};
+use libreplex_shared::wrapped_sol;
 use rarible_editions::{
     group_extension_program,
     program::RaribleEditions, 
     EditionsDeployment,
-    cpi::accounts::MintCtx
+    BurnInput,
+    cpi::accounts::{BurnCtx, MintCtx}
 };
 use crate::{
     EditionsControls,
     MinterStats,
+    PricingMode,
     errors::EditionsControlsError,
     check_phase_constraints,
-    check_allow_list_constraints
+    check_allow_list_constraints,
+    check_token_gate_constraints,
+    mark_gate_token_used,
+    get_phase_price,
+    check_cosign_constraints,
+    CosignInput,
+    GateType
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -23,6 +35,14 @@ pub struct MintInput {
     pub merkle_proof: Option<Vec<[u8; 32]>>,
     pub allow_list_price: Option<u64>,
     pub allow_list_max_claims: Option<u64>,
+    // number of editions to mint, every edition after the first one passes its
+    // mint, member, hashlist_marker, token_account, hashlist_page (and extra_metas_account
+    // when royalties are enforced) in the remaining accounts
+    pub quantity: u32,
+    // hashlist position of the burned edition, BurnEdition phases only
+    pub gate_hashlist_index: Option<u32>,
+    // required when editions_controls.cosigner_program_id is set
+    pub cosign: Option<CosignInput>,
 }
 
 #[derive(Accounts)]
@@ -42,6 +62,10 @@ pub struct MintWithControlsCtx<'info> {
     #[account(mut)]
     pub hashlist: UncheckedAccount<'info>,
 
+    // CHECK: Checked via CPI
+    #[account(mut)]
+    pub hashlist_page: UncheckedAccount<'info>,
+
     // CHECK: Checked via CPI
     #[account(mut)]
     pub hashlist_marker: UncheckedAccount<'info>,
@@ -49,12 +73,8 @@ pub struct MintWithControlsCtx<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// When deployment.require_creator_cosign is true, this must be equal to the creator
-    /// of the deployment; otherwise, can be any signer account
-    #[account(
-        constraint = editions_controls.cosigner_program_id == system_program::ID
-            || signer.key() == editions_deployment.creator
-    )]
+    /// Can be any signer account, when editions_controls.cosigner_program_id is set the mint
+    /// is authorised by its ed25519 co-signature instead
     pub signer: Signer<'info>,
 
     // CHECK: Anybody can sign, anybody can receive the inscription
@@ -104,14 +124,6 @@ pub struct MintWithControlsCtx<'info> {
     )]
     pub group_mint: UncheckedAccount<'info>,
 
-    /// CHECK: Platform fee recipient
-    #[account(mut)]
-    pub platform_fee_recipient_1: UncheckedAccount<'info>,
-
-    // TODO add optimization for stack
-    // #[account(mut)]
-    // pub platform_fee_recipient_2: UncheckedAccount<'info>,
-
     /// CHECK: Passed in via CPI to mpl_token_metadata program
     #[account(mut)]
     pub token_account: UncheckedAccount<'info>,
@@ -129,6 +141,62 @@ pub struct MintWithControlsCtx<'info> {
     )]
     pub token_program: UncheckedAccount<'info>,
 
+    /* SPL PAYMENT ACCOUNTS - only required when the phase is not priced in native SOL */
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    // token account of the payer, debited for the price and platform fees
+    #[account(mut)]
+    pub payer_payment_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // token account of the treasury, receives the mint proceeds
+    #[account(mut)]
+    pub treasury_payment_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /* TOKEN GATE ACCOUNTS - only required when the phase is token gated */
+    // token account of the minter holding the gating token
+    #[account(mut)]
+    pub gate_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: account holding the TokenGroupMember of the gating nft, checked in check_token_gate_constraints
+    pub gate_member: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: GateMarker PDA of the gating nft, created in mark_gate_token_used
+    #[account(mut)]
+    pub gate_marker: Option<UncheckedAccount<'info>>,
+
+    /* BURN-TO-MINT ACCOUNTS - only required when the phase burns the gating token */
+    // mint of the burned token
+    #[account(mut)]
+    pub gate_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub gate_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: deployment of the burned edition, checked against the phase and via CPI
+    #[account(mut)]
+    pub gate_deployment: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked via CPI
+    #[account(mut)]
+    pub gate_hashlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked via CPI, only required when the burned edition is recorded in a hashlist page
+    #[account(mut)]
+    pub gate_hashlist_page: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked via CPI
+    #[account(mut)]
+    pub gate_hashlist_marker: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked via CPI, only required when editions have a transfer hook
+    #[account(mut)]
+    pub extra_metas_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: address checked, only required when editions_controls.cosigner_program_id is set
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
 
     /// CHECK: Address checked
@@ -140,14 +208,19 @@ pub struct MintWithControlsCtx<'info> {
     pub rarible_editions_program: Program<'info, RaribleEditions>,
 }
 
-pub fn mint_with_controls(
-    ctx: Context<MintWithControlsCtx>,
+pub fn mint_with_controls<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintWithControlsCtx<'info>>,
     mint_input: MintInput,
 ) -> Result<()> {
     let editions_controls = &mut ctx.accounts.editions_controls;
     let minter_stats = &mut ctx.accounts.minter_stats;
     let minter_stats_phase = &mut ctx.accounts.minter_stats_phase;
     let minter = &ctx.accounts.minter;
+    let quantity = mint_input.quantity as u64;
+
+    if quantity == 0 {
+        return Err(EditionsControlsError::InvalidQuantity.into());
+    }
 
     // Phase validation
     validate_phase(editions_controls, mint_input.phase_index)?;
@@ -158,10 +231,52 @@ pub fn mint_with_controls(
         minter_stats,
         minter_stats_phase,
         editions_controls,
+        quantity,
     )?;
 
-    // Get the default/standard price amount for the phase
-    let mut price_amount = editions_controls.phases[mint_input.phase_index as usize].price_amount;
+    // Check the backend co-signature
+    if editions_controls.cosigner_program_id != system_program::ID {
+        check_cosign_constraints(
+            &editions_controls.cosigner_program_id,
+            &editions_controls.key(),
+            &minter.key(),
+            mint_input.phase_index,
+            minter_stats,
+            mint_input.cosign.clone(),
+            ctx.accounts.instructions_sysvar.as_ref(),
+        )?;
+    }
+
+    // Check token gate constraints
+    let phase = &editions_controls.phases[mint_input.phase_index as usize];
+    let (gate_type, gate_key, gate_burn_amount) = (phase.gate_type, phase.gate_key, phase.gate_min_balance.saturating_mul(quantity));
+    if phase.gate_type != GateType::None {
+        let gate_mint = check_token_gate_constraints(
+            phase,
+            &minter.key(),
+            ctx.accounts.gate_token_account.as_deref(),
+            ctx.accounts.gate_member.as_ref(),
+            quantity,
+        )?;
+
+        if phase.gate_single_use {
+            let gate_marker = ctx.accounts.gate_marker.as_ref().ok_or(EditionsControlsError::MissingGateAccounts)?;
+            mark_gate_token_used(
+                &gate_marker.to_account_info(),
+                &gate_mint,
+                &minter.key(),
+                &editions_controls.key(),
+                mint_input.phase_index,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+        }
+    }
+
+    // Get the default/standard price amount for the phase (the current auction price for dutch auctions)
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut price_amount = get_phase_price(&editions_controls.phases[mint_input.phase_index as usize], current_time)?;
+    let mut is_auction_mint = editions_controls.phases[mint_input.phase_index as usize].pricing_mode == PricingMode::DutchAuction;
 
     // Check if it's a normal mint or an allow list mint based on the presence of a merkle proof
     if mint_input.merkle_proof.is_some() {
@@ -172,9 +287,11 @@ pub fn mint_with_controls(
             mint_input.merkle_proof,
             mint_input.allow_list_price,
             mint_input.allow_list_max_claims,
+            quantity,
         )?;
         // Override the price amount with the allow list price
         price_amount = mint_input.allow_list_price.unwrap_or(0);
+        is_auction_mint = false;
     } else {
         // if the phase is private, and the merkle proof was not provided, throw error
         if editions_controls.phases[mint_input.phase_index as usize].is_private {
@@ -189,10 +306,36 @@ pub fn mint_with_controls(
         &minter.key(),
         editions_controls,
         mint_input.phase_index as usize,
+        quantity,
     );
 
-    // Process platform fees and transfer remaining amount to treasury
-    process_platform_fees(&ctx, price_amount)?;
+    let phase = &mut editions_controls.phases[mint_input.phase_index as usize];
+    let price_token = phase.price_token;
+    let is_rebate_mint = is_auction_mint && phase.rebate_enabled;
+    if is_auction_mint {
+        // the price only goes down, so the last auction mint sets the clearing price
+        phase.clearing_price = price_amount;
+    }
+
+    // Fees are charged once on the total price of all editions
+    let total_price = price_amount
+        .checked_mul(quantity)
+        .ok_or(EditionsControlsError::FeeCalculationError)?;
+
+    // Process platform fees and transfer remaining amount to treasury.
+    // Rebate mints keep the remaining amount in escrow until the clearing price is final.
+    let proceeds = process_platform_fees(&ctx, &price_token, total_price, quantity, is_rebate_mint)?;
+
+    if is_rebate_mint {
+        let minter_stats_phase = &mut ctx.accounts.minter_stats_phase;
+        minter_stats_phase.amount_paid = minter_stats_phase.amount_paid
+            .checked_add(total_price)
+            .ok_or(EditionsControlsError::FeeCalculationError)?;
+        minter_stats_phase.rebate_mints = minter_stats_phase.rebate_mints.saturating_add(quantity);
+        minter_stats_phase.escrowed_amount = minter_stats_phase.escrowed_amount
+            .checked_add(proceeds)
+            .ok_or(EditionsControlsError::FeeCalculationError)?;
+    }
 
     // Prepare seeds for signer
     let editions_deployment_key = ctx.accounts.editions_deployment.key();
@@ -202,8 +345,56 @@ pub fn mint_with_controls(
         &[ctx.bumps.editions_controls],
     ];
 
+    // Burn-to-mint phases burn the gating token before minting
+    if gate_type == GateType::BurnEdition || gate_type == GateType::BurnToken {
+        burn_gate_token(&ctx, gate_type, &gate_key, gate_burn_amount, mint_input.gate_hashlist_index)?;
+    }
+
     // Perform the minting process
-    perform_mint(&ctx, seeds)?;
+    perform_mint(
+        &ctx,
+        seeds,
+        EditionAccounts {
+            mint: ctx.accounts.mint.to_account_info(),
+            member: ctx.accounts.member.to_account_info(),
+            hashlist_marker: ctx.accounts.hashlist_marker.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            hashlist_page: ctx.accounts.hashlist_page.to_account_info(),
+            extra_metas_account: ctx.accounts.extra_metas_account.as_ref().map(|a| a.to_account_info()),
+        },
+    )?;
+
+    // Additional editions follow the platform fee recipients in the remaining accounts
+    let fee_recipient_count = ctx.accounts.editions_controls.platform_fee_recipients
+        .iter()
+        .filter(|r| r.share > 0)
+        .count();
+    let edition_accounts = ctx.remaining_accounts
+        .get(fee_recipient_count..)
+        .unwrap_or(&[]);
+    // editions with enforced royalties also pass their extra account metas account
+    let accounts_per_edition = match ctx.accounts.editions_deployment.enforce_royalties {
+        true => 6,
+        false => 5,
+    };
+    if edition_accounts.len() != (quantity as usize - 1) * accounts_per_edition {
+        return Err(EditionsControlsError::MissingMintAccounts.into());
+    }
+
+    for accounts in edition_accounts.chunks(accounts_per_edition) {
+        perform_mint(
+            &ctx,
+            seeds,
+            EditionAccounts {
+                mint: accounts[0].clone(),
+                member: accounts[1].clone(),
+                hashlist_marker: accounts[2].clone(),
+                token_account: accounts[3].clone(),
+                hashlist_page: accounts[4].clone(),
+                extra_metas_account: accounts.get(5).cloned(),
+            },
+        )?;
+    }
 
     Ok(())
 }
@@ -229,25 +420,27 @@ fn update_minter_and_phase_stats(
     minter_key: &Pubkey,
     editions_controls: &mut EditionsControls,
     phase_index: usize,
+    quantity: u64,
 ) {
     minter_stats.wallet = *minter_key;
-    minter_stats.mint_count = minter_stats.mint_count.saturating_add(1);
+    minter_stats.mint_count = minter_stats.mint_count.saturating_add(quantity);
 
     minter_stats_phase.wallet = *minter_key;
-    minter_stats_phase.mint_count = minter_stats_phase.mint_count.saturating_add(1);
+    minter_stats_phase.mint_count = minter_stats_phase.mint_count.saturating_add(quantity);
 
     editions_controls.phases[phase_index].current_mints =
-        editions_controls.phases[phase_index].current_mints.saturating_add(1);
+        editions_controls.phases[phase_index].current_mints.saturating_add(quantity);
 }
 
-fn process_platform_fees(
-    ctx: &Context<MintWithControlsCtx>,
+fn process_platform_fees<'info>(
+    ctx: &Context<'_, '_, '_, 'info, MintWithControlsCtx<'info>>,
+    price_token: &Pubkey,
     price_amount: u64,
-) -> Result<()> {
+    quantity: u64,
+    escrow_proceeds: bool,
+) -> Result<u64> {
     let editions_controls = &ctx.accounts.editions_controls;
-    let payer = &ctx.accounts.payer;
     let treasury = &ctx.accounts.treasury;
-    let system_program = &ctx.accounts.system_program;
     let recipients = &editions_controls.platform_fee_recipients;
 
     // Ensure that the sum of shares equals 100
@@ -260,7 +453,10 @@ fn process_platform_fees(
     let remaining_amount: u64;
 
     if editions_controls.is_fee_flat {
-        total_fee = editions_controls.platform_fee_value;
+        // the flat fee applies to every edition
+        total_fee = editions_controls.platform_fee_value
+            .checked_mul(quantity)
+            .ok_or(EditionsControlsError::FeeCalculationError)?;
         remaining_amount = price_amount;
     } else {
         // Calculate fee as (price_amount * platform_fee_value) / 10,000 (assuming basis points)
@@ -274,17 +470,38 @@ fn process_platform_fees(
             .ok_or(EditionsControlsError::FeeCalculationError)?;
     }
 
-    // Distribute fees to recipients
-    for (i, recipient_struct) in recipients.iter().enumerate() {
-        if recipient_struct.share == 0 {
-            continue;
-        }
+    let is_native = price_token.eq(&wrapped_sol::ID);
+    if !is_native {
+        validate_payment_accounts(ctx, price_token)?;
+    }
 
-        let recipient_account = &ctx.accounts.platform_fee_recipient_1;
+    // Distribute fees to recipients. Every recipient with a non-zero share is passed in the
+    // remaining accounts, in the order they are stored: the wallet itself for native phases,
+    // otherwise the recipient's token account for the price token.
+    let mut recipient_accounts = ctx.remaining_accounts.iter();
+    let mut payouts: Vec<(AccountInfo<'info>, u64)> = vec![];
+    let mut distributed_fee: u64 = 0;
+    for recipient_struct in recipients.iter().filter(|r| r.share > 0) {
+        let recipient_account = recipient_accounts
+            .next()
+            .ok_or(EditionsControlsError::MissingFeeRecipient)?;
 
         // Ensure that the account matches the expected recipient
-        if recipient_account.key() != recipient_struct.address.key() {
-            return Err(EditionsControlsError::RecipientMismatch.into());
+        if is_native {
+            if recipient_account.key() != recipient_struct.address {
+                return Err(EditionsControlsError::RecipientMismatch.into());
+            }
+        } else {
+            // validated in validate_payment_accounts
+            let payment_token_program = ctx.accounts.payment_token_program.as_ref().unwrap();
+            if recipient_account.owner != &payment_token_program.key() {
+                return Err(EditionsControlsError::RecipientMismatch.into());
+            }
+            let recipient_payment_account = TokenAccount::try_deserialize(&mut &recipient_account.try_borrow_data()?[..])?;
+            if recipient_payment_account.owner != recipient_struct.address
+                || recipient_payment_account.mint != *price_token {
+                return Err(EditionsControlsError::RecipientMismatch.into());
+            }
         }
 
         let recipient_fee = total_fee
@@ -293,39 +510,240 @@ fn process_platform_fees(
             .checked_div(100)
             .ok_or(EditionsControlsError::FeeCalculationError)?;
 
-        // Transfer platform fee to recipient
-        system_program::transfer(
+        distributed_fee = distributed_fee
+            .checked_add(recipient_fee)
+            .ok_or(EditionsControlsError::FeeCalculationError)?;
+        payouts.push((recipient_account.clone(), recipient_fee));
+    }
+
+    // Rounding dust goes to the first recipient so that the split always adds up to the full fee
+    if let Some((_, first_recipient_fee)) = payouts.first_mut() {
+        *first_recipient_fee = first_recipient_fee
+            .checked_add(total_fee.saturating_sub(distributed_fee))
+            .ok_or(EditionsControlsError::FeeCalculationError)?;
+    }
+
+    // Transfer platform fees to recipients
+    for (recipient_account, recipient_fee) in payouts {
+        transfer_payment(ctx, price_token, &recipient_account, recipient_fee)?;
+    }
+
+    // Transfer remaining amount to treasury (or to the minter's phase stats account while a rebate is pending)
+    if escrow_proceeds {
+        // rebate phases are always native, checked in add_phase
+        transfer_payment(ctx, price_token, &ctx.accounts.minter_stats_phase.to_account_info(), remaining_amount)?;
+    } else if is_native {
+        transfer_payment(ctx, price_token, &treasury.to_account_info(), remaining_amount)?;
+    } else {
+        // validated in validate_payment_accounts
+        let treasury_payment_account = ctx.accounts.treasury_payment_account.as_ref().unwrap();
+        transfer_payment(ctx, price_token, &treasury_payment_account.to_account_info(), remaining_amount)?;
+    }
+
+    Ok(remaining_amount)
+}
+
+/// Checks the spl payment accounts against the phase price token. The payer funds the mint
+/// from its own token account, exactly as it funds native mints from its lamports.
+fn validate_payment_accounts(
+    ctx: &Context<MintWithControlsCtx>,
+    price_token: &Pubkey,
+) -> Result<()> {
+    let (Some(payment_mint), Some(payer_payment_account), Some(treasury_payment_account), Some(payment_token_program)) = (
+        ctx.accounts.payment_mint.as_ref(),
+        ctx.accounts.payer_payment_account.as_ref(),
+        ctx.accounts.treasury_payment_account.as_ref(),
+        ctx.accounts.payment_token_program.as_ref(),
+    ) else {
+        return Err(EditionsControlsError::MissingPaymentAccounts.into());
+    };
+
+    if payment_mint.key() != *price_token {
+        return Err(EditionsControlsError::PaymentMintMismatch.into());
+    }
+
+    // works for both spl token and token-2022 mints
+    if payment_mint.to_account_info().owner != &payment_token_program.key() {
+        return Err(EditionsControlsError::PaymentMintMismatch.into());
+    }
+
+    if payer_payment_account.owner != ctx.accounts.payer.key() || payer_payment_account.mint != *price_token {
+        return Err(EditionsControlsError::PaymentAccountMismatch.into());
+    }
+
+    if treasury_payment_account.owner != ctx.accounts.treasury.key() || treasury_payment_account.mint != *price_token {
+        return Err(EditionsControlsError::PaymentAccountMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Pays `amount` from the payer to `to`. For native phases `to` is a wallet and lamports are
+/// moved, otherwise `to` is a token account and the price token is moved with transfer_checked.
+/// For token-2022 mints with a transfer fee the payer also pays the fee, so that `to` is
+/// credited the full `amount`.
+fn transfer_payment<'info>(
+    ctx: &Context<'_, '_, '_, 'info, MintWithControlsCtx<'info>>,
+    price_token: &Pubkey,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if price_token.eq(&wrapped_sol::ID) {
+        return system_program::transfer(
             CpiContext::new(
-                system_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
                 system_program::Transfer {
-                    from: payer.to_account_info(),
-                    to: recipient_account.to_account_info(),
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: to.clone(),
                 },
             ),
-            recipient_fee,
-        )?;
+            amount,
+        );
+    }
 
-        break;
+    // validated in validate_payment_accounts
+    let payment_mint = ctx.accounts.payment_mint.as_ref().unwrap();
+    let payer_payment_account = ctx.accounts.payer_payment_account.as_ref().unwrap();
+    let payment_token_program = ctx.accounts.payment_token_program.as_ref().unwrap();
+
+    let fee = get_transfer_fee(&payment_mint.to_account_info(), amount)?;
+    if fee > 0 {
+        return transfer_checked_with_fee(
+            CpiContext::new(
+                payment_token_program.to_account_info(),
+                TransferCheckedWithFee {
+                    token_program_id: payment_token_program.to_account_info(),
+                    source: payer_payment_account.to_account_info(),
+                    mint: payment_mint.to_account_info(),
+                    destination: to.clone(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount.checked_add(fee).ok_or(EditionsControlsError::FeeCalculationError)?,
+            payment_mint.decimals,
+            fee,
+        );
     }
 
-    // Transfer remaining amount to treasury
-    system_program::transfer(
+    transfer_checked(
         CpiContext::new(
-            system_program.to_account_info(),
-            system_program::Transfer {
-                from: payer.to_account_info(),
-                to: treasury.to_account_info(),
+            payment_token_program.to_account_info(),
+            TransferChecked {
+                from: payer_payment_account.to_account_info(),
+                mint: payment_mint.to_account_info(),
+                to: to.clone(),
+                authority: ctx.accounts.payer.to_account_info(),
             },
         ),
-        remaining_amount,
-    )?;
+        amount,
+        payment_mint.decimals,
+    )
+}
 
-    Ok(())
+/// Transfer fee the token program withholds when `to` is to be credited `amount`, 0 for mints
+/// without a TransferFeeConfig.
+fn get_transfer_fee(payment_mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if payment_mint.owner != &TOKEN_2022_ID {
+        return Ok(0);
+    }
+
+    let data = payment_mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let Ok(transfer_fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    Ok(transfer_fee_config
+        .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(EditionsControlsError::FeeCalculationError)?)
 }
 
-fn perform_mint(
-    ctx: &Context<MintWithControlsCtx>,
+/// Burns the gating token of a burn-to-mint phase. Editions are burned through rarible_editions so
+/// that their hashlist marker and hashlist entry are updated, any other token is burned directly.
+fn burn_gate_token<'info>(
+    ctx: &Context<'_, '_, '_, 'info, MintWithControlsCtx<'info>>,
+    gate_type: GateType,
+    gate_key: &Pubkey,
+    burn_amount: u64,
+    gate_hashlist_index: Option<u32>,
+) -> Result<()> {
+    // only the holder can burn
+    if !ctx.accounts.minter.is_signer {
+        return Err(EditionsControlsError::BurnRequiresMinterSignature.into());
+    }
+
+    let (Some(gate_token_account), Some(gate_mint)) = (
+        ctx.accounts.gate_token_account.as_ref(),
+        ctx.accounts.gate_mint.as_ref(),
+    ) else {
+        return Err(EditionsControlsError::MissingGateAccounts.into());
+    };
+
+    if gate_type == GateType::BurnEdition {
+        let (Some(gate_deployment), Some(gate_hashlist), Some(gate_hashlist_marker), Some(hashlist_index)) = (
+            ctx.accounts.gate_deployment.as_ref(),
+            ctx.accounts.gate_hashlist.as_ref(),
+            ctx.accounts.gate_hashlist_marker.as_ref(),
+            gate_hashlist_index,
+        ) else {
+            return Err(EditionsControlsError::MissingGateAccounts.into());
+        };
+
+        if gate_deployment.key() != *gate_key {
+            return Err(EditionsControlsError::TokenGateNotMet.into());
+        }
+
+        return rarible_editions::cpi::burn(
+            CpiContext::new(
+                ctx.accounts.rarible_editions_program.to_account_info(),
+                BurnCtx {
+                    editions_deployment: gate_deployment.to_account_info(),
+                    hashlist: gate_hashlist.to_account_info(),
+                    hashlist_page: ctx.accounts.gate_hashlist_page.as_ref().map(|a| a.to_account_info()),
+                    hashlist_marker: gate_hashlist_marker.to_account_info(),
+                    owner: ctx.accounts.minter.to_account_info(),
+                    mint: gate_mint.to_account_info(),
+                    token_account: gate_token_account.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ),
+            BurnInput { hashlist_index },
+        );
+    }
+
+    let gate_token_program = ctx.accounts.gate_token_program.as_ref().ok_or(EditionsControlsError::MissingGateAccounts)?;
+
+    token_interface::burn(
+        CpiContext::new(
+            gate_token_program.to_account_info(),
+            Burn {
+                mint: gate_mint.to_account_info(),
+                from: gate_token_account.to_account_info(),
+                authority: ctx.accounts.minter.to_account_info(),
+            },
+        ),
+        burn_amount,
+    )
+}
+
+// the accounts that differ between the editions minted in one instruction
+struct EditionAccounts<'info> {
+    mint: AccountInfo<'info>,
+    member: AccountInfo<'info>,
+    hashlist_marker: AccountInfo<'info>,
+    token_account: AccountInfo<'info>,
+    hashlist_page: AccountInfo<'info>,
+    extra_metas_account: Option<AccountInfo<'info>>,
+}
+
+fn perform_mint<'info>(
+    ctx: &Context<'_, '_, '_, 'info, MintWithControlsCtx<'info>>,
     seeds: &[&[u8]],
+    edition: EditionAccounts<'info>,
 ) -> Result<()> {
     let rarible_editions_program = &ctx.accounts.rarible_editions_program;
     let editions_controls = &ctx.accounts.editions_controls;
@@ -336,19 +754,21 @@ fn perform_mint(
             MintCtx {
                 editions_deployment: ctx.accounts.editions_deployment.to_account_info(),
                 hashlist: ctx.accounts.hashlist.to_account_info(),
-                hashlist_marker: ctx.accounts.hashlist_marker.to_account_info(),
+                hashlist_page: edition.hashlist_page,
+                hashlist_marker: edition.hashlist_marker,
                 payer: ctx.accounts.payer.to_account_info(),
                 signer: editions_controls.to_account_info(),
                 minter: ctx.accounts.minter.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
+                mint: edition.mint,
                 group: ctx.accounts.group.to_account_info(),
                 group_mint: ctx.accounts.group_mint.to_account_info(),
-                token_account: ctx.accounts.token_account.to_account_info(),
+                token_account: edition.token_account,
+                extra_metas_account: edition.extra_metas_account,
                 token_program: ctx.accounts.token_program.to_account_info(),
                 associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
                 system_program: ctx.accounts.system_program.to_account_info(),
                 group_extension_program: ctx.accounts.group_extension_program.to_account_info(),
-                member: ctx.accounts.member.to_account_info(),
+                member: edition.member,
             },
             &[seeds],
         ),
@@ -4,6 +4,42 @@ pub use initialise::*;
 pub mod add_phase;
 pub use add_phase::*;
 
+pub mod update_phase;
+pub use update_phase::*;
+
+pub mod set_phase_active;
+pub use set_phase_active::*;
+
+pub mod remove_phase;
+pub use remove_phase::*;
+
+pub mod claim_rebate;
+pub use claim_rebate::*;
+
+pub mod update_cosigner;
+pub use update_cosigner::*;
+
+pub mod set_deployment_status;
+pub use set_deployment_status::*;
+
+pub mod propose_creator;
+pub use propose_creator::*;
+
+pub mod accept_creator;
+pub use accept_creator::*;
+
+pub mod renounce_creator;
+pub use renounce_creator::*;
+
+pub mod reveal;
+pub use reveal::*;
+
+pub mod update_collection_metadata;
+pub use update_collection_metadata::*;
+
+pub mod update_item_metadata;
+pub use update_item_metadata::*;
+
 pub mod update_royalties;
 pub use update_royalties::*;
 
@@ -14,4 +50,13 @@ pub mod update_platform_fee;
 pub use update_platform_fee::*;
 
 pub mod update_platform_fee_secondary_admin;
-pub use update_platform_fee_secondary_admin::*;
\ No newline at end of file
+pub use update_platform_fee_secondary_admin::*;
+
+pub mod revoke;
+pub use revoke::*;
+
+pub mod thaw_editions;
+pub use thaw_editions::*;
+
+pub mod update_max_supply;
+pub use update_max_supply::*;
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{CreatorProposed, EditionsControls, PendingCreator};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ProposeCreatorInput {
+    pub new_creator: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(input: ProposeCreatorInput)]
+pub struct ProposeCreatorCtx<'info> {
+    pub editions_controls: Account<'info, EditionsControls>,
+
+    // a new proposal replaces the previous one
+    #[account(init_if_needed,
+        payer = payer,
+        space = PendingCreator::SIZE,
+        seeds = [b"pending_creator", editions_controls.key().as_ref()],
+        bump)]
+    pub pending_creator: Account<'info, PendingCreator>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_creator(ctx: Context<ProposeCreatorCtx>, input: ProposeCreatorInput) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+
+    ctx.accounts.pending_creator.set_inner(PendingCreator {
+        editions_controls: editions_controls.key(),
+        creator: editions_controls.creator,
+        pending_creator: input.new_creator,
+    });
+
+    emit!(CreatorProposed {
+        editions_controls: editions_controls.key(),
+        creator: editions_controls.creator,
+        pending_creator: input.new_creator,
+    });
+
+    Ok(())
+}
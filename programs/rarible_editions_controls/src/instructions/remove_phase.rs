@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{EditionsControls, errors::EditionsControlsError};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct RemovePhaseInput {
+    pub phase_index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(input: RemovePhaseInput)]
+pub struct RemovePhaseCtx<'info> {
+    #[account(mut)]
+    pub editions_controls: Account<'info, EditionsControls>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+}
+
+/*
+    minter_stats_phase PDAs are keyed by phase index, so phases are never shifted.
+    The last phase is dropped outright if nobody has minted from it yet (no stats
+    exist for its index, and the slot is reused by the next add_phase). Any other
+    phase is tombstoned in place: it is deactivated and can no longer be minted
+    from, updated or reactivated.
+*/
+pub fn remove_phase(ctx: Context<RemovePhaseCtx>, input: RemovePhaseInput) -> Result<()> {
+    let editions_controls = &mut ctx.accounts.editions_controls;
+    let phase_index = input.phase_index as usize;
+    let is_last_phase = phase_index + 1 == editions_controls.phases.len();
+
+    let phase = editions_controls.phases
+        .get_mut(phase_index)
+        .ok_or(EditionsControlsError::InvalidPhaseIndex)?;
+
+    if phase.removed {
+        return Err(EditionsControlsError::PhaseRemoved.into());
+    }
+
+    if phase.current_mints == 0 && is_last_phase {
+        editions_controls.phases.pop();
+    } else {
+        phase.active = false;
+        phase.removed = true;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{CreatorRenounced, EditionsControls};
+
+#[derive(Accounts)]
+pub struct RenounceCreatorCtx<'info> {
+    #[account(mut)]
+    pub editions_controls: Account<'info, EditionsControls>,
+
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+}
+
+/*
+    Sets the creator to the default pubkey, which nobody can sign for. Phases, metadata,
+    royalties and the cosigner are locked from then on, minting carries on as configured.
+    The controls PDA stays the creator of the editions deployment.
+*/
+pub fn renounce_creator(ctx: Context<RenounceCreatorCtx>) -> Result<()> {
+    let editions_controls = &mut ctx.accounts.editions_controls;
+
+    let previous_creator = editions_controls.creator;
+    editions_controls.creator = Pubkey::default();
+
+    emit!(CreatorRenounced {
+        editions_controls: editions_controls.key(),
+        previous_creator,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use rarible_editions::{BurnInput, EditionsDeployment};
+use rarible_editions::program::RaribleEditions;
+use rarible_editions::cpi::accounts::RevokeCtx;
+use crate::EditionsControls;
+
+#[derive(Accounts)]
+#[instruction(input: BurnInput)]
+pub struct RevokeWithControlsCtx<'info> {
+    #[account(mut)]
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    /// CHECK: Checked via CPI
+    #[account(mut)]
+    pub hashlist: UncheckedAccount<'info>,
+
+    /// CHECK: Checked via CPI, only required for mints recorded in a hashlist page
+    #[account(mut)]
+    pub hashlist_page: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Checked via CPI
+    #[account(mut)]
+    pub hashlist_marker: UncheckedAccount<'info>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Checked via CPI
+    #[account(mut)]
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: address checked
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+
+    pub rarible_editions_program: Program<'info, RaribleEditions>
+}
+
+pub fn revoke(ctx: Context<RevokeWithControlsCtx>, input: BurnInput) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let editions_deployment_key = editions_deployment.key();
+    let seeds = &[
+        b"editions_controls",
+        editions_deployment_key.as_ref(),
+        &[ctx.bumps.editions_controls],
+    ];
+
+    rarible_editions::cpi::revoke(
+        CpiContext::new_with_signer(
+            ctx.accounts.rarible_editions_program.to_account_info(),
+            RevokeCtx {
+                editions_deployment: editions_deployment.to_account_info(),
+                hashlist: ctx.accounts.hashlist.to_account_info(),
+                hashlist_page: ctx.accounts.hashlist_page.as_ref().map(|a| a.to_account_info()),
+                hashlist_marker: ctx.accounts.hashlist_marker.to_account_info(),
+                creator: editions_controls.to_account_info(),
+                holder: ctx.accounts.holder.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &[seeds]
+        ), input)?;
+
+    Ok(())
+}
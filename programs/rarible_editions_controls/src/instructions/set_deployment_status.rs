@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use rarible_editions::{EditionsDeployment, SetDeploymentStatusInput};
+use rarible_editions::program::RaribleEditions;
+use rarible_editions::cpi::accounts::SetDeploymentStatusCtx;
+use crate::EditionsControls;
+
+#[derive(Accounts)]
+#[instruction(input: SetDeploymentStatusInput)]
+pub struct SetDeploymentStatusWithControlsCtx<'info> {
+    #[account(mut)]
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    pub rarible_editions_program: Program<'info, RaribleEditions>
+}
+
+pub fn set_deployment_status(ctx: Context<SetDeploymentStatusWithControlsCtx>, input: SetDeploymentStatusInput) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let editions_deployment_key = editions_deployment.key();
+    let seeds = &[
+        b"editions_controls",
+        editions_deployment_key.as_ref(),
+        &[ctx.bumps.editions_controls],
+    ];
+
+    rarible_editions::cpi::set_deployment_status(
+        CpiContext::new_with_signer(
+            ctx.accounts.rarible_editions_program.to_account_info(),
+            SetDeploymentStatusCtx {
+                editions_deployment: editions_deployment.to_account_info(),
+                signer: editions_controls.to_account_info(),
+            },
+            &[seeds]
+        ), input)?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{EditionsControls, errors::EditionsControlsError};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SetPhaseActiveInput {
+    pub phase_index: u32,
+    pub active: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(input: SetPhaseActiveInput)]
+pub struct SetPhaseActiveCtx<'info> {
+    #[account(mut)]
+    pub editions_controls: Account<'info, EditionsControls>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+}
+
+// pauses (active = false) or resumes (active = true) a phase
+pub fn set_phase_active(ctx: Context<SetPhaseActiveCtx>, input: SetPhaseActiveInput) -> Result<()> {
+    let editions_controls = &mut ctx.accounts.editions_controls;
+
+    let phase = editions_controls.phases
+        .get_mut(input.phase_index as usize)
+        .ok_or(EditionsControlsError::InvalidPhaseIndex)?;
+
+    if phase.removed {
+        return Err(EditionsControlsError::PhaseRemoved.into());
+    }
+
+    phase.active = input.active;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use rarible_editions::EditionsDeployment;
+use rarible_editions::program::RaribleEditions;
+use rarible_editions::cpi::accounts::ThawEditionsCtx;
+use crate::EditionsControls;
+
+#[derive(Accounts)]
+pub struct ThawEditionsWithControlsCtx<'info> {
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    /// CHECK: address checked
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: AccountInfo<'info>,
+
+    pub rarible_editions_program: Program<'info, RaribleEditions>
+}
+
+// token accounts and their mints are passed through in the remaining accounts
+pub fn thaw_editions<'info>(ctx: Context<'_, '_, '_, 'info, ThawEditionsWithControlsCtx<'info>>) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let editions_deployment_key = editions_deployment.key();
+    let seeds = &[
+        b"editions_controls",
+        editions_deployment_key.as_ref(),
+        &[ctx.bumps.editions_controls],
+    ];
+
+    rarible_editions::cpi::thaw_editions(
+        CpiContext::new_with_signer(
+            ctx.accounts.rarible_editions_program.to_account_info(),
+            ThawEditionsCtx {
+                editions_deployment: editions_deployment.to_account_info(),
+                creator: editions_controls.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &[seeds]
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()))?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Token2022;
+use rarible_editions::{EditionsDeployment, UpdateCollectionMetadataArgs};
+use rarible_editions::program::RaribleEditions;
+use rarible_editions::cpi::accounts::UpdateCollectionMetadata;
+use crate::EditionsControls;
+
+#[derive(Accounts)]
+#[instruction(input: UpdateCollectionMetadataArgs)]
+pub struct UpdateCollectionMetadataCtx<'info> {
+    #[account(mut)]
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Checked via CPI
+    #[account(mut)]
+    pub group_mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub rarible_editions_program: Program<'info, RaribleEditions>
+}
+
+pub fn update_collection_metadata(ctx: Context<UpdateCollectionMetadataCtx>, input: UpdateCollectionMetadataArgs) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let editions_deployment_key = editions_deployment.key();
+    let seeds = &[
+        b"editions_controls",
+        editions_deployment_key.as_ref(),
+        &[ctx.bumps.editions_controls],
+    ];
+
+    rarible_editions::cpi::update_collection_metadata(
+        CpiContext::new_with_signer(
+            ctx.accounts.rarible_editions_program.to_account_info(),
+            UpdateCollectionMetadata {
+                editions_deployment: editions_deployment.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                signer: editions_controls.to_account_info(),
+                group_mint: ctx.accounts.group_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &[seeds]
+        ), input)?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::EditionsControls;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct UpdateCosignerInput {
+    pub cosigner: Option<Pubkey>, // None to mint without a cosigner
+}
+
+#[derive(Accounts)]
+#[instruction(input: UpdateCosignerInput)]
+pub struct UpdateCosignerCtx<'info> {
+    #[account(mut)]
+    pub editions_controls: Account<'info, EditionsControls>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+}
+
+pub fn update_cosigner(ctx: Context<UpdateCosignerCtx>, input: UpdateCosignerInput) -> Result<()> {
+    let editions_controls = &mut ctx.accounts.editions_controls;
+    editions_controls.cosigner_program_id = input.cosigner.unwrap_or(system_program::ID);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+use rarible_editions::{EditionsDeployment, UpdateItemMetadataArgs};
+use rarible_editions::program::RaribleEditions;
+use rarible_editions::cpi::accounts::{UpdateItemMetadata, UpdateItemMetadataBatch};
+use crate::EditionsControls;
+
+#[derive(Accounts)]
+#[instruction(input: UpdateItemMetadataArgs)]
+pub struct UpdateItemMetadataCtx<'info> {
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    /// CHECK: Checked via CPI
+    pub hashlist_marker: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub rarible_editions_program: Program<'info, RaribleEditions>
+}
+
+#[derive(Accounts)]
+#[instruction(input: Vec<UpdateItemMetadataArgs>)]
+pub struct UpdateItemMetadataBatchCtx<'info> {
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub rarible_editions_program: Program<'info, RaribleEditions>
+}
+
+pub fn update_item_metadata(ctx: Context<UpdateItemMetadataCtx>, input: UpdateItemMetadataArgs) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let editions_deployment_key = editions_deployment.key();
+    let seeds = &[
+        b"editions_controls",
+        editions_deployment_key.as_ref(),
+        &[ctx.bumps.editions_controls],
+    ];
+
+    rarible_editions::cpi::update_item_metadata(
+        CpiContext::new_with_signer(
+            ctx.accounts.rarible_editions_program.to_account_info(),
+            UpdateItemMetadata {
+                editions_deployment: editions_deployment.to_account_info(),
+                hashlist_marker: ctx.accounts.hashlist_marker.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                signer: editions_controls.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &[seeds]
+        ), input)?;
+
+    Ok(())
+}
+
+// mints and hashlist markers are passed through in the remaining accounts
+pub fn update_item_metadata_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateItemMetadataBatchCtx<'info>>,
+    input: Vec<UpdateItemMetadataArgs>,
+) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let editions_deployment_key = editions_deployment.key();
+    let seeds = &[
+        b"editions_controls",
+        editions_deployment_key.as_ref(),
+        &[ctx.bumps.editions_controls],
+    ];
+
+    rarible_editions::cpi::update_item_metadata_batch(
+        CpiContext::new_with_signer(
+            ctx.accounts.rarible_editions_program.to_account_info(),
+            UpdateItemMetadataBatch {
+                editions_deployment: editions_deployment.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                signer: editions_controls.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            &[seeds]
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()), input)?;
+
+    Ok(())
+}
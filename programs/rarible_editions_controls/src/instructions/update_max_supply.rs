@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use rarible_editions::{group_extension_program, EditionsDeployment, UpdateMaxSupplyInput};
+use rarible_editions::program::RaribleEditions;
+use rarible_editions::cpi::accounts::UpdateMaxSupplyCtx;
+use crate::EditionsControls;
+
+#[derive(Accounts)]
+#[instruction(input: UpdateMaxSupplyInput)]
+pub struct UpdateMaxSupplyWithControlsCtx<'info> {
+    #[account(mut)]
+    pub editions_deployment: Box<Account<'info, EditionsDeployment>>,
+
+    #[account(
+        seeds = [b"editions_controls", editions_deployment.key().as_ref()],
+        bump
+    )]
+    pub editions_controls: Box<Account<'info, EditionsControls>>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Checked via CPI
+    #[account(mut)]
+    pub group: UncheckedAccount<'info>,
+
+    /// CHECK: address checked
+    #[account(address = group_extension_program::ID)]
+    pub group_extension_program: AccountInfo<'info>,
+
+    pub rarible_editions_program: Program<'info, RaribleEditions>
+}
+
+pub fn update_max_supply(ctx: Context<UpdateMaxSupplyWithControlsCtx>, input: UpdateMaxSupplyInput) -> Result<()> {
+    let editions_controls = &ctx.accounts.editions_controls;
+    let editions_deployment = &ctx.accounts.editions_deployment;
+
+    let editions_deployment_key = editions_deployment.key();
+    let seeds = &[
+        b"editions_controls",
+        editions_deployment_key.as_ref(),
+        &[ctx.bumps.editions_controls],
+    ];
+
+    rarible_editions::cpi::update_max_supply(
+        CpiContext::new_with_signer(
+            ctx.accounts.rarible_editions_program.to_account_info(),
+            UpdateMaxSupplyCtx {
+                editions_deployment: editions_deployment.to_account_info(),
+                signer: editions_controls.to_account_info(),
+                group: ctx.accounts.group.to_account_info(),
+                group_extension_program: ctx.accounts.group_extension_program.to_account_info(),
+            },
+            &[seeds]
+        ), input)?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{EditionsControls, errors::EditionsControlsError};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct UpdatePhaseInput {
+    pub phase_index: u32,
+    pub price_amount: u64,
+    pub start_time: i64,
+    pub max_mints_per_wallet: u64,
+    pub max_mints_total: u64, // set to 0 for unlimited, otherwise must be >= current_mints
+    pub end_time: i64, // must be after start_time
+    pub merkle_root: Option<[u8; 32]>,
+}
+
+#[derive(Accounts)]
+#[instruction(input: UpdatePhaseInput)]
+pub struct UpdatePhaseCtx<'info> {
+    #[account(mut)]
+    pub editions_controls: Account<'info, EditionsControls>,
+
+    // can be different from payer for PDA integration
+    #[account(
+        constraint = editions_controls.creator == creator.key())]
+    pub creator: Signer<'info>,
+}
+
+pub fn update_phase(ctx: Context<UpdatePhaseCtx>, input: UpdatePhaseInput) -> Result<()> {
+    let editions_controls = &mut ctx.accounts.editions_controls;
+
+    let phase = editions_controls.phases
+        .get_mut(input.phase_index as usize)
+        .ok_or(EditionsControlsError::InvalidPhaseIndex)?;
+
+    if phase.removed {
+        return Err(EditionsControlsError::PhaseRemoved.into());
+    }
+
+    if input.end_time <= input.start_time {
+        return Err(EditionsControlsError::InvalidPhaseTimes.into());
+    }
+
+    if input.max_mints_total > 0 && input.max_mints_total < phase.current_mints {
+        return Err(EditionsControlsError::MaxMintsBelowCurrentMints.into());
+    }
+
+    let rebate_locked = phase.is_rebate_locked(Clock::get()?.unix_timestamp);
+    if rebate_locked && (input.end_time != phase.end_time || input.max_mints_total != phase.max_mints_total) {
+        return Err(EditionsControlsError::RebatePhaseLocked.into());
+    }
+
+    if phase.is_private && input.merkle_root.is_none() {
+        return Err(EditionsControlsError::MerkleRootRequiredForPrivatePhase.into());
+    }
+
+    // price_token and is_private are fixed once the phase is created
+    phase.price_amount = input.price_amount;
+    phase.start_time = input.start_time;
+    phase.end_time = input.end_time;
+    phase.max_mints_per_wallet = input.max_mints_per_wallet;
+    phase.max_mints_total = input.max_mints_total;
+    phase.merkle_root = input.merkle_root;
+
+    // a new start price can drop below the floor of a dutch auction
+    if !phase.has_valid_dutch_auction() {
+        return Err(EditionsControlsError::InvalidDutchAuction.into());
+    }
+
+    Ok(())
+}
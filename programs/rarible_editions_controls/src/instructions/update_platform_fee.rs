@@ -3,6 +3,7 @@ use rarible_editions::{EditionsDeployment};
 use rarible_editions::program::RaribleEditions;
 use anchor_spl::token_interface::{Mint};
 use crate::{EditionsControls, PlatformFeeRecipient, UpdatePlatformFeeArgs};
+use crate::errors::EditionsControlsError;
 
 #[derive(Accounts)]
 #[instruction(input: UpdatePlatformFeeArgs)]
@@ -34,6 +35,17 @@ pub fn update_platform_fee(ctx: Context<UpdatePlatformFeeCtx>, platform_fee_inpu
 
     let editions_controls = &mut ctx.accounts.editions_controls;
 
+    // Validate that platform_fee has up to 5 recipients
+    if platform_fee_input.recipients.len() > 5 {
+        return Err(EditionsControlsError::TooManyRecipients.into());
+    }
+
+    // Ensure that the sum of shares equals 100, every recipient gets paid on each mint
+    let total_shares: u8 = platform_fee_input.recipients.iter().map(|r| r.share).sum();
+    if total_shares != 100 {
+        return Err(EditionsControlsError::InvalidFeeShares.into());
+    }
+
     // Initialize an array of 5 PlatformFeeRecipient with default values
     let mut recipients_array: [PlatformFeeRecipient; 5] = [
         PlatformFeeRecipient {
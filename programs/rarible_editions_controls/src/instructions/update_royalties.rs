@@ -17,6 +17,10 @@ pub struct UpdateRoyaltiesCtx<'info> {
     )]
     pub editions_controls: Box<Account<'info, EditionsControls>>,
 
+    /// CHECK: Checked via CPI, created there for deployments that predate royalty configs
+    #[account(mut)]
+    pub royalty_config: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -64,6 +68,7 @@ pub fn update_royalties(ctx: Context<UpdateRoyaltiesCtx>, royalties_input: Updat
             rarible_editions_program.to_account_info(),
             ModifyRoyalties {
                 editions_deployment: editions_deployment.to_account_info(),
+                royalty_config: ctx.accounts.royalty_config.to_account_info(),
                 payer: payer.to_account_info(),
                 signer: editions_controls.to_account_info(),
                 mint: mint.to_account_info(),
@@ -8,10 +8,13 @@ pub use instructions::*;
 declare_id!("DtdtodSsgeNXbu3rycGCkJEpRuZ48rjFSBfgj8JKzTV2");
 
 pub mod errors;
+pub mod events;
 pub mod state;
 
 pub use state::*;
 
+pub use events::*;
+
 #[program]
 pub mod rarible_editions_controls {
     use super::*;
@@ -34,10 +37,103 @@ pub mod rarible_editions_controls {
         instructions::add_phase(ctx, input)
     }
 
+    pub fn update_phase(
+        ctx: Context<UpdatePhaseCtx>,
+        input: UpdatePhaseInput) -> Result<()> {
+        instructions::update_phase(ctx, input)
+    }
+
+    pub fn set_phase_active(
+        ctx: Context<SetPhaseActiveCtx>,
+        input: SetPhaseActiveInput) -> Result<()> {
+        instructions::set_phase_active(ctx, input)
+    }
+
+    pub fn remove_phase(
+        ctx: Context<RemovePhaseCtx>,
+        input: RemovePhaseInput) -> Result<()> {
+        instructions::remove_phase(ctx, input)
+    }
+
     pub fn mint_with_controls<'info>(ctx: Context<'_, '_, '_, 'info, MintWithControlsCtx<'info>>, mint_input: MintInput) -> Result<()> {
         instructions::mint_with_controls(ctx, mint_input)
     }
 
+    pub fn claim_rebate(
+        ctx: Context<ClaimRebateCtx>,
+        input: ClaimRebateInput) -> Result<()> {
+        instructions::claim_rebate(ctx, input)
+    }
+
+    pub fn update_cosigner(
+        ctx: Context<UpdateCosignerCtx>,
+        input: UpdateCosignerInput) -> Result<()> {
+        instructions::update_cosigner(ctx, input)
+    }
+
+    pub fn set_deployment_status(
+        ctx: Context<SetDeploymentStatusWithControlsCtx>,
+        input: rarible_editions::SetDeploymentStatusInput) -> Result<()> {
+        instructions::set_deployment_status(ctx, input)
+    }
+
+    pub fn update_max_supply(
+        ctx: Context<UpdateMaxSupplyWithControlsCtx>,
+        input: rarible_editions::UpdateMaxSupplyInput) -> Result<()> {
+        instructions::update_max_supply(ctx, input)
+    }
+
+    pub fn propose_creator(
+        ctx: Context<ProposeCreatorCtx>,
+        input: ProposeCreatorInput) -> Result<()> {
+        instructions::propose_creator(ctx, input)
+    }
+
+    pub fn accept_creator(
+        ctx: Context<AcceptCreatorCtx>) -> Result<()> {
+        instructions::accept_creator(ctx)
+    }
+
+    pub fn renounce_creator(
+        ctx: Context<RenounceCreatorCtx>) -> Result<()> {
+        instructions::renounce_creator(ctx)
+    }
+
+    pub fn reveal(
+        ctx: Context<RevealWithControlsCtx>,
+        input: rarible_editions::RevealInput) -> Result<()> {
+        instructions::reveal(ctx, input)
+    }
+
+    pub fn revoke(
+        ctx: Context<RevokeWithControlsCtx>,
+        input: rarible_editions::BurnInput) -> Result<()> {
+        instructions::revoke(ctx, input)
+    }
+
+    pub fn thaw_editions<'info>(
+        ctx: Context<'_, '_, '_, 'info, ThawEditionsWithControlsCtx<'info>>) -> Result<()> {
+        instructions::thaw_editions(ctx)
+    }
+
+    pub fn update_collection_metadata(
+        ctx: Context<UpdateCollectionMetadataCtx>,
+        input: rarible_editions::UpdateCollectionMetadataArgs) -> Result<()> {
+        instructions::update_collection_metadata(ctx, input)
+    }
+
+    pub fn update_item_metadata(
+        ctx: Context<UpdateItemMetadataCtx>,
+        input: rarible_editions::UpdateItemMetadataArgs) -> Result<()> {
+        instructions::update_item_metadata(ctx, input)
+    }
+
+    pub fn update_item_metadata_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateItemMetadataBatchCtx<'info>>,
+        input: Vec<rarible_editions::UpdateItemMetadataArgs>) -> Result<()> {
+        instructions::update_item_metadata_batch(ctx, input)
+    }
+
     pub fn modify_royalties(
         ctx: Context<UpdateRoyaltiesCtx>,
         input: rarible_editions::UpdateRoyaltiesArgs) -> Result<()> {
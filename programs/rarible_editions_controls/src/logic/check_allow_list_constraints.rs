@@ -21,13 +21,14 @@ pub fn check_allow_list_constraints(
     merkle_proof: Option<Vec<[u8; 32]>>,
     allow_list_price: Option<u64>,
     allow_list_max_claims: Option<u64>,
+    quantity: u64,
 ) -> Result<()> {
     if let Some(merkle_root) = phase.merkle_root {
         if let Some(proof) = merkle_proof {
             if let (Some(phase_list_price), Some(phase_max_claims)) = (allow_list_price, allow_list_max_claims) {
                 /// 1. check constraints
                 /// dev: notice that if phase_max_claims is 0, this constraint is disabled
-                if phase_max_claims > 0 && minter_stats_phase.mint_count >= phase_max_claims {
+                if phase_max_claims > 0 && minter_stats_phase.mint_count.saturating_add(quantity) > phase_max_claims {
                     return Err(EditionsControlsError::ExceededAllowListMaxClaims.into());
                 }
 
@@ -0,0 +1,98 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+use crate::{
+    MinterStats,
+    errors::EditionsControlsError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CosignInput {
+    pub expiry: i64, // unix timestamp after which the co-signature is rejected
+    pub nonce: u64, // must be higher than the last nonce used by the minter
+}
+
+// ed25519 instruction layout: count (u8), padding (u8), then 14 bytes of offsets per signature
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+
+/// Checks that the instruction right before mint_with_controls is an ed25519 program instruction
+/// in which the cosigner signed: editions_controls | minter | phase_index | expiry | nonce
+pub fn check_cosign_constraints(
+    cosigner: &Pubkey,
+    editions_controls: &Pubkey,
+    minter: &Pubkey,
+    phase_index: u32,
+    minter_stats: &mut Account<MinterStats>,
+    cosign: Option<CosignInput>,
+    instructions_sysvar: Option<&UncheckedAccount>,
+) -> Result<()> {
+    let (Some(cosign), Some(instructions_sysvar)) = (cosign, instructions_sysvar) else {
+        return Err(EditionsControlsError::CosignatureRequired.into());
+    };
+
+    if Clock::get()?.unix_timestamp > cosign.expiry {
+        return Err(EditionsControlsError::CosignatureExpired.into());
+    }
+
+    if cosign.nonce <= minter_stats.last_cosign_nonce {
+        return Err(EditionsControlsError::CosignNonceReused.into());
+    }
+
+    let instructions_sysvar = instructions_sysvar.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(EditionsControlsError::CosignatureRequired.into());
+    }
+
+    let ed25519_ix = load_instruction_at_checked(current_index as usize - 1, &instructions_sysvar)?;
+    if ed25519_ix.program_id != ed25519_program::ID || !ed25519_ix.accounts.is_empty() {
+        return Err(EditionsControlsError::CosignatureRequired.into());
+    }
+
+    let mut expected_message = Vec::with_capacity(32 + 32 + 4 + 8 + 8);
+    expected_message.extend_from_slice(editions_controls.as_ref());
+    expected_message.extend_from_slice(minter.as_ref());
+    expected_message.extend_from_slice(&phase_index.to_le_bytes());
+    expected_message.extend_from_slice(&cosign.expiry.to_le_bytes());
+    expected_message.extend_from_slice(&cosign.nonce.to_le_bytes());
+
+    let data = &ed25519_ix.data;
+    if data.len() < SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE || data[0] != 1 {
+        return Err(EditionsControlsError::InvalidCosignature.into());
+    }
+
+    let read_u16 = |pos: usize| u16::from_le_bytes([data[pos], data[pos + 1]]);
+    let offsets = SIGNATURE_OFFSETS_START;
+    let signature_instruction_index = read_u16(offsets + 2);
+    let public_key_offset = read_u16(offsets + 4) as usize;
+    let public_key_instruction_index = read_u16(offsets + 6);
+    let message_data_offset = read_u16(offsets + 8) as usize;
+    let message_data_size = read_u16(offsets + 10) as usize;
+    let message_instruction_index = read_u16(offsets + 12);
+
+    // signature, key and message must all live in the ed25519 instruction itself
+    if signature_instruction_index != u16::MAX
+        || public_key_instruction_index != u16::MAX
+        || message_instruction_index != u16::MAX {
+        return Err(EditionsControlsError::InvalidCosignature.into());
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + PUBKEY_SIZE)
+        .ok_or(EditionsControlsError::InvalidCosignature)?;
+    let message = data.get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(EditionsControlsError::InvalidCosignature)?;
+
+    if public_key != cosigner.as_ref() || message != expected_message.as_slice() {
+        return Err(EditionsControlsError::InvalidCosignature.into());
+    }
+
+    minter_stats.last_cosign_nonce = cosign.nonce;
+
+    Ok(())
+}
@@ -12,10 +12,15 @@ pub fn check_phase_constraints(
     minter_stats: &mut Account<MinterStats>,
     minter_stats_phase: &mut Account<MinterStats>,
     editions_controls: &Account<EditionsControls>,
+    quantity: u64,
 ) -> Result<()> {
     let clock = Clock::get().unwrap();
     let current_time = clock.unix_timestamp;
 
+    if phase.removed {
+        return Err(EditionsControlsError::PhaseRemoved.into());
+    }
+
     if !phase.active {
         return Err(EditionsControlsError::PhaseNotActive.into());
     }
@@ -28,21 +33,21 @@ pub fn check_phase_constraints(
         return Err(EditionsControlsError::PhaseAlreadyFinished.into());
     }
 
-    /// Checks if the total mints for the phase has been exceeded (phase sold out)
+    /// Checks if the total mints for the phase would be exceeded (phase sold out)
     /// @dev dev: notice that if max_mints_total is 0, this constraint is disabled
-    if phase.max_mints_total > 0 && phase.current_mints >= phase.max_mints_total {
+    if phase.max_mints_total > 0 && phase.current_mints.saturating_add(quantity) > phase.max_mints_total {
         return Err(EditionsControlsError::ExceededMaxMintsForPhase.into());
     }
 
-    /// Checks if the user has exceeded the max mints for the deployment (across all phases!)
+    /// Checks if the user would exceed the max mints for the deployment (across all phases!)
     /// dev: notice that if max_mints_per_wallet is 0, this constraint is disabled
-    if editions_controls.max_mints_per_wallet > 0 && minter_stats.mint_count >= editions_controls.max_mints_per_wallet {
+    if editions_controls.max_mints_per_wallet > 0 && minter_stats.mint_count.saturating_add(quantity) > editions_controls.max_mints_per_wallet {
         return Err(EditionsControlsError::ExceededWalletMaxMintsForCollection.into());
     }
 
-    /// Checks if the user has exceeded the max mints for the current phase
+    /// Checks if the user would exceed the max mints for the current phase
     /// dev: notice that if max_mints_per_wallet is 0, this constraint is disabled
-    if phase.max_mints_per_wallet > 0 && minter_stats_phase.mint_count >= phase.max_mints_per_wallet {
+    if phase.max_mints_per_wallet > 0 && minter_stats_phase.mint_count.saturating_add(quantity) > phase.max_mints_per_wallet {
         return Err(EditionsControlsError::ExceededWalletMaxMintsForPhase.into());
     }
 
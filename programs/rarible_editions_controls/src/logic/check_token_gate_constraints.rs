@@ -0,0 +1,163 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::hash,
+    system_program,
+};
+use anchor_spl::token_interface::TokenAccount;
+use rarible_editions::group_extension_program;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_group_interface::state::TokenGroupMember;
+use crate::{
+    GateMarker, GateType, Phase,
+    errors::EditionsControlsError,
+};
+
+/// Checks that the minter holds the token required by a gated phase and returns the mint of
+/// the holding account.
+pub fn check_token_gate_constraints(
+    phase: &Phase,
+    minter: &Pubkey,
+    gate_token_account: Option<&InterfaceAccount<TokenAccount>>,
+    gate_member: Option<&UncheckedAccount>,
+    quantity: u64,
+) -> Result<Pubkey> {
+    let gate_token_account = gate_token_account.ok_or(EditionsControlsError::MissingGateAccounts)?;
+
+    if gate_token_account.owner != *minter {
+        return Err(EditionsControlsError::TokenGateNotMet.into());
+    }
+
+    match phase.gate_type {
+        GateType::None => {},
+        GateType::GroupMember => {
+            if gate_token_account.amount == 0 {
+                return Err(EditionsControlsError::TokenGateNotMet.into());
+            }
+
+            // one gating nft per edition when the gate is single use
+            if phase.gate_single_use && quantity > 1 {
+                return Err(EditionsControlsError::GateSingleUseQuantity.into());
+            }
+
+            let gate_member = gate_member.ok_or(EditionsControlsError::MissingGateAccounts)?;
+            let member = read_group_member(&gate_member.to_account_info())?;
+
+            if member.mint != gate_token_account.mint || member.group != phase.gate_key {
+                return Err(EditionsControlsError::TokenGateNotMet.into());
+            }
+        },
+        GateType::TokenBalance => {
+            if gate_token_account.mint != phase.gate_key || gate_token_account.amount < phase.gate_min_balance {
+                return Err(EditionsControlsError::TokenGateNotMet.into());
+            }
+        },
+        // the edition belongs to the deployment if its hashlist marker is found when burning
+        GateType::BurnEdition => {
+            if gate_token_account.amount == 0 {
+                return Err(EditionsControlsError::TokenGateNotMet.into());
+            }
+
+            // one burned edition per edition
+            if quantity > 1 {
+                return Err(EditionsControlsError::GateSingleUseQuantity.into());
+            }
+        },
+        GateType::BurnToken => {
+            let burn_amount = phase.gate_min_balance.saturating_mul(quantity);
+            if gate_token_account.mint != phase.gate_key || gate_token_account.amount < burn_amount {
+                return Err(EditionsControlsError::TokenGateNotMet.into());
+            }
+        },
+    }
+
+    Ok(gate_token_account.mint)
+}
+
+/// Reads the TokenGroupMember of an nft. The member lives either in a separate account of the
+/// group extension program (as for rarible_editions), or in the token-2022 mint itself.
+fn read_group_member(member_account: &AccountInfo) -> Result<TokenGroupMember> {
+    let data = member_account.try_borrow_data()?;
+
+    if member_account.owner == &group_extension_program::ID {
+        // single tlv entry: 8 byte discriminator, 4 byte length, then the member itself
+        let discriminator = &hash(b"spl_token_group_interface:member").to_bytes()[..8];
+        let value_end = 12 + std::mem::size_of::<TokenGroupMember>();
+        if data.len() < value_end || &data[..8] != discriminator {
+            return Err(EditionsControlsError::TokenGateNotMet.into());
+        }
+        return Ok(*spl_pod::bytemuck::pod_from_bytes::<TokenGroupMember>(&data[12..value_end])?);
+    }
+
+    if member_account.owner == &spl_token_2022::ID {
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        return Ok(*state.get_extension::<TokenGroupMember>()?);
+    }
+
+    Err(EditionsControlsError::TokenGateNotMet.into())
+}
+
+/// Creates the GateMarker PDA of a gating nft, failing if it already exists.
+pub fn mark_gate_token_used<'info>(
+    gate_marker: &AccountInfo<'info>,
+    gate_mint: &Pubkey,
+    minter: &Pubkey,
+    editions_controls: &Pubkey,
+    phase_index: u32,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let phase_index_bytes = phase_index.to_le_bytes();
+    let (expected_marker, bump) = Pubkey::find_program_address(
+        &[b"gate_marker", editions_controls.as_ref(), &phase_index_bytes, gate_mint.as_ref()],
+        &crate::ID,
+    );
+    if gate_marker.key() != expected_marker {
+        return Err(EditionsControlsError::MissingGateAccounts.into());
+    }
+
+    if gate_marker.owner == &crate::ID {
+        return Err(EditionsControlsError::GateTokenAlreadyUsed.into());
+    }
+
+    let seeds: &[&[u8]] = &[b"gate_marker", editions_controls.as_ref(), &phase_index_bytes, gate_mint.as_ref(), &[bump]];
+    let required_lamports = Rent::get()?.minimum_balance(GateMarker::SIZE);
+
+    // the marker address may have been funded up front, so allocate and assign instead of create_account
+    let lamports_diff = required_lamports.saturating_sub(gate_marker.lamports());
+    if lamports_diff > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: gate_marker.clone(),
+                },
+            ),
+            lamports_diff,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: gate_marker.clone() },
+            &[seeds],
+        ),
+        GateMarker::SIZE as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: gate_marker.clone() },
+            &[seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    let marker = GateMarker {
+        gate_mint: *gate_mint,
+        minter: *minter,
+    };
+    marker.try_serialize(&mut &mut gate_marker.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
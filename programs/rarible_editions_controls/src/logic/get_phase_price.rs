@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Phase, PricingMode,
+    errors::EditionsControlsError,
+};
+
+/// Returns the price of a single mint in the phase at `current_time`.
+/// @dev dev: for dutch auctions the price drops by price_decrement once per elapsed step and never goes below floor_price
+pub fn get_phase_price(phase: &Phase, current_time: i64) -> Result<u64> {
+    match phase.pricing_mode {
+        PricingMode::Fixed => Ok(phase.price_amount),
+        PricingMode::DutchAuction => {
+            let elapsed = current_time.saturating_sub(phase.start_time).max(0);
+            let steps = (elapsed / phase.step_seconds) as u64;
+            let drop = steps.saturating_mul(phase.price_decrement);
+
+            Ok(phase.price_amount.saturating_sub(drop).max(phase.floor_price))
+        }
+    }
+}
+
+/// Returns the part of the escrowed proceeds owed back to the minter once the clearing price is known.
+/// @dev dev: the escrow holds the proceeds net of platform fees, so the rebate is scaled down by the
+/// same ratio and the minter does not get back fees that were already paid out
+pub fn get_rebate_amount(phase: &Phase, amount_paid: u64, rebate_mints: u64, escrowed_amount: u64) -> Result<u64> {
+    if amount_paid == 0 {
+        return Ok(0);
+    }
+
+    let clearing_total = (phase.clearing_price as u128)
+        .checked_mul(rebate_mints as u128)
+        .ok_or(EditionsControlsError::FeeCalculationError)?;
+    let overpaid = (amount_paid as u128).saturating_sub(clearing_total);
+
+    let rebate = overpaid
+        .checked_mul(escrowed_amount as u128)
+        .ok_or(EditionsControlsError::FeeCalculationError)?
+        .checked_div(amount_paid as u128)
+        .ok_or(EditionsControlsError::FeeCalculationError)?;
+
+    Ok((rebate as u64).min(escrowed_amount))
+}
@@ -3,3 +3,12 @@ pub use check_phase_constraints::*;
 
 pub mod check_allow_list_constraints;
 pub use check_allow_list_constraints::*;
+
+pub mod get_phase_price;
+pub use get_phase_price::*;
+
+pub mod check_token_gate_constraints;
+pub use check_token_gate_constraints::*;
+
+pub mod check_cosign_constraints;
+pub use check_cosign_constraints::*;
@@ -20,10 +20,25 @@ impl PlatformFeeRecipient {
     + 1; // share
 }
 
+#[derive(Clone, Copy, AnchorDeserialize, AnchorSerialize, PartialEq, Eq)]
+pub enum PricingMode {
+    Fixed, // must stay first so that phases created before pricing modes read as fixed price
+    DutchAuction,
+}
+
+#[derive(Clone, Copy, AnchorDeserialize, AnchorSerialize, PartialEq, Eq)]
+pub enum GateType {
+    None, // must stay first so that phases created before token gates read as ungated
+    GroupMember, // minter holds an nft whose TokenGroupMember points to gate_key (the group)
+    TokenBalance, // minter holds at least gate_min_balance of the gate_key mint
+    BurnEdition, // minter burns an edition of the gate_key rarible_editions deployment
+    BurnToken, // minter burns gate_min_balance of the gate_key mint (mint pass) per edition
+}
+
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub struct Phase {
     pub price_amount: u64,
-    pub price_token: Pubkey, // SO111111 - native ß
+    pub price_token: Pubkey, // SO111111 - native SOL, otherwise the spl / token-2022 mint to pay in
     pub start_time: i64, // set to any date before now for instant activate
     pub active: bool,
     pub max_mints_per_wallet: u64, // set to 0 for unlimited
@@ -32,10 +47,39 @@ pub struct Phase {
     pub current_mints: u64,
     pub is_private: bool,
     pub merkle_root: Option<[u8; 32]>,
-    pub padding: [u8; 200]
+    // removed phases keep their slot so that phase indices (and minter_stats_phase seeds) stay stable
+    pub removed: bool,
+    // dutch auction: price_amount is the start price and drops by price_decrement every step_seconds down to floor_price
+    pub pricing_mode: PricingMode,
+    pub floor_price: u64,
+    pub step_seconds: i64,
+    pub price_decrement: u64,
+    pub rebate_enabled: bool, // early buyers can claim back the difference down to clearing_price
+    pub clearing_price: u64, // price of the last auction mint
+    // token gate, checked against the gate accounts of mint_with_controls
+    pub gate_type: GateType,
+    pub gate_key: Pubkey, // group for GroupMember, deployment for BurnEdition, mint otherwise
+    pub gate_min_balance: u64,
+    pub gate_single_use: bool, // each gating nft can be used once per phase, tracked with a GateMarker
+    pub rebate_claimed: bool, // set by the first claim_rebate, the clearing price can no longer move
+    pub padding: [u8; 122]
 }
 
 impl Phase {
+    /// End time and max mints total of a rebate auction decide its clearing price, they are frozen once
+    /// the phase has ended or sold out, or a rebate has been claimed.
+    pub fn is_rebate_locked(&self, current_time: i64) -> bool {
+        let sold_out = self.max_mints_total > 0 && self.current_mints >= self.max_mints_total;
+        self.rebate_enabled && (self.rebate_claimed || self.end_time <= current_time || sold_out)
+    }
+
+    /// A dutch auction has to step down in time, by a non-zero amount, from a start price
+    /// (price_amount) no lower than its floor. Fixed price phases are always valid.
+    pub fn has_valid_dutch_auction(&self) -> bool {
+        self.pricing_mode != PricingMode::DutchAuction
+            || (self.step_seconds > 0 && self.price_decrement > 0 && self.floor_price <= self.price_amount)
+    }
+
     pub const SIZE: usize = 8 // discriminator
     + 8 // price_amount
     + 32 // price_token
@@ -47,7 +91,19 @@ impl Phase {
     + 8 // current_mints
     + 1 // is_private
     + 32 + 1 // merkle_root
-    + 200; // padding
+    + 1 // removed
+    + 1 // pricing_mode
+    + 8 // floor_price
+    + 8 // step_seconds
+    + 8 // price_decrement
+    + 1 // rebate_enabled
+    + 8 // clearing_price
+    + 1 // gate_type
+    + 32 // gate_key
+    + 8 // gate_min_balance
+    + 1 // gate_single_use
+    + 1 // rebate_claimed
+    + 122; // padding
 }
 
 pub const DEFAULT_PLATFORM_FEE_PRIMARY_ADMIN: &str = "674s1Sap3KVnr8WGrY5KGQ69oTYjjgr1disKJo6GpTYw";
@@ -57,14 +113,51 @@ pub const DEFAULT_PLATFORM_FEE_SECONDARY_ADMIN: &str = "9qGioKcyuh1mwXS8MbmMgdtQ
 pub struct MinterStats {
     pub wallet: Pubkey,
     pub mint_count: u64, 
-    pub padding: [u8; 50]
+    // dutch auction rebates, only used on minter_stats_phase
+    pub amount_paid: u64, // sum of the auction prices paid
+    pub rebate_mints: u64, // number of auction mints eligible for a rebate
+    pub escrowed_amount: u64, // lamports held by this account until the rebate is claimed
+    pub last_cosign_nonce: u64, // only used on minter_stats, cosigned mints need a higher nonce
+    pub padding: [u8; 18]
 }
 
 impl MinterStats {
     pub const SIZE: usize = 8 // discriminator
     + 32 // wallet
     + 8 // mint_count
-    + 50; // padding
+    + 8 // amount_paid
+    + 8 // rebate_mints
+    + 8 // escrowed_amount
+    + 8 // last_cosign_nonce
+    + 18; // padding
+}
+
+// marks a gating nft as used in a phase
+#[account]
+pub struct GateMarker {
+    pub gate_mint: Pubkey,
+    pub minter: Pubkey,
+}
+
+impl GateMarker {
+    pub const SIZE: usize = 8 // discriminator
+    + 32 // gate_mint
+    + 32; // minter
+}
+
+// creator handoff waiting for the new creator to accept, ["pending_creator", editions_controls]
+#[account]
+pub struct PendingCreator {
+    pub editions_controls: Pubkey,
+    pub creator: Pubkey, // creator at the time of the proposal, the proposal lapses when the creator changes
+    pub pending_creator: Pubkey,
+}
+
+impl PendingCreator {
+    pub const SIZE: usize = 8 // discriminator
+    + 32 // editions_controls
+    + 32 // creator
+    + 32; // pending_creator
 }
 
 #[account]
@@ -73,7 +166,7 @@ pub struct EditionsControls {
     pub creator: Pubkey,
     pub treasury: Pubkey, // mint proceeds go here
     pub max_mints_per_wallet: u64, // set to 0 for unlimited (applied across all the phases)
-    pub cosigner_program_id: Pubkey,
+    pub cosigner_program_id: Pubkey, // backend key that co-signs every mint, system program id for no cosigner
     pub platform_fee_primary_admin: Pubkey,
     pub platform_fee_secondary_admin: Pubkey,
     pub platform_fee_value: u64, // Fee amount or basis points
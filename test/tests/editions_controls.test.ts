@@ -1,15 +1,45 @@
 import * as anchor from '@coral-xyz/anchor';
 import { Program } from '@coral-xyz/anchor';
-import { PublicKey, Keypair, SystemProgram, ComputeBudgetProgram, LAMPORTS_PER_SOL } from '@solana/web3.js';
-import { ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  ComputeBudgetProgram,
+  LAMPORTS_PER_SOL,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+} from '@solana/web3.js';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccount,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createInitializeMetadataPointerInstruction,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  ExtensionType,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  getTransferFeeAmount,
+  mintTo,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  tokenMetadataInitializeWithRentTransfer,
+  tokenMetadataUpdateAuthority,
+  tokenMetadataUpdateFieldWithRentTransfer,
+} from '@solana/spl-token';
 import { RaribleEditionsControls } from '../../target/types/rarible_editions_controls';
 import { RaribleEditions } from '../../target/types/rarible_editions';
 import { expect } from 'chai';
+import { createHash } from 'crypto';
 import { describe, it } from 'mocha';
 import { estimateTransactionFee, getCluster } from '../utils/utils';
 import {
   getEditions,
   getEditionsControls,
+  getHashlistEntries,
   getMinterStats,
   getTokenMetadata,
   logEditions,
@@ -18,11 +48,30 @@ import {
   logMinterStatsPhase,
   logTokenMetadata,
 } from '../utils/getters';
-import { Transaction } from '@solana/web3.js';
+import { Transaction, TransactionInstruction } from '@solana/web3.js';
 // devnote: try to make tests don't rely on hard addresses but on dynamic runtime ids.
 import { TOKEN_GROUP_EXTENSION_PROGRAM_ID } from '../../constants';
-import { getEditionsPda, getEditionsControlsPda, getHashlistPda, getHashlistMarkerPda, getMinterStatsPda, getMinterStatsPhasePda } from '../utils/pdas';
+import { getEditionsPda, getEditionsControlsPda, getHashlistPda, getHashlistPagePda, getHashlistMarkerPda, getMinterStatsPda, getMinterStatsPhasePda, getRoyaltyConfigPda, getGateMarkerPda, getApproveAccountPda, HASHLIST_PAGE_SIZE } from '../utils/pdas';
 import { CollectionConfig, AllowListConfig, PhaseConfig } from '../utils/types';
+import {
+  addPhase,
+  airdrop,
+  deployEditions,
+  deployWithControls,
+  EditionAccounts,
+  editionSigners,
+  expectError,
+  mintEditionIx,
+  mintWithControlsIx,
+  newEdition,
+  now,
+  phaseConfig,
+  send,
+  sleep,
+  TestDeployment,
+  transferEditionIxs,
+  waitForClusterTime,
+} from '../utils/fixtures';
 
 const VERBOSE_LOGGING = false;
 
@@ -41,6 +90,10 @@ describe('Editions Controls Test Suite', () => {
   let editionsPda: PublicKey;
   let editionsControlsPda: PublicKey;
   let hashlistPda: PublicKey;
+  let hashlistPagePda: PublicKey;
+  let royaltyConfigPda: PublicKey;
+  // first edition minted by minter0, burned at the end of the suite
+  let firstMint: PublicKey;
 
   let payer: Keypair;
   let creator1: Keypair;
@@ -129,6 +182,9 @@ describe('Editions Controls Test Suite', () => {
     editionsPda = getEditionsPda(collectionConfig.symbol, editionsProgram.programId);
     editionsControlsPda = getEditionsControlsPda(editionsPda, editionsControlsProgram.programId);
     hashlistPda = getHashlistPda(editionsPda, editionsProgram.programId);
+    // the test mints stay well below HASHLIST_PAGE_SIZE, so every mint lands on the first page
+    hashlistPagePda = getHashlistPagePda(editionsPda, 0, editionsProgram.programId);
+    royaltyConfigPda = getRoyaltyConfigPda(editionsPda, editionsProgram.programId);
   });
 
   // Generate allowlist variables
@@ -222,15 +278,29 @@ describe('Editions Controls Test Suite', () => {
             extraMeta: collectionConfig.extraMeta,
             itemBaseUri: collectionConfig.itemBaseUri,
             itemBaseName: collectionConfig.itemBaseName,
+            provenanceHash: null,
+            randomNumbering: false,
+            enforceRoyalties: false,
+            soulbound: false,
+            revocable: false,
+            mintCloseAuthority: false,
+            defaultAccountFrozen: false,
+            requireTransferMemo: false,
+            freezeUntilMintOut: false,
+            unlockTime: null,
+            mintStartTime: null,
+            mintEndTime: null,
           })
           .accountsStrict({
             editionsControls: editionsControlsPda,
             editionsDeployment: editionsPda,
             hashlist: hashlistPda,
+            royaltyConfig: royaltyConfigPda,
             payer: payer.publicKey,
             creator: payer.publicKey,
             groupMint: groupMint.publicKey,
             group: group.publicKey,
+            tokenIndices: null,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             raribleEditionsProgram: editionsProgram.programId,
@@ -275,6 +345,11 @@ describe('Editions Controls Test Suite', () => {
           expect(metadata.additionalMetadata).to.have.property(meta.field);
           expect(metadata.additionalMetadata[meta.field]).to.equal(meta.value);
         });
+        // Verify royalties, creators are written as creator:<index> = <address>:<share>:<verified>
+        expect(metadata.additionalMetadata['royalty_basis_points']).to.equal(collectionConfig.royalties.royaltyBasisPoints.toString());
+        collectionConfig.royalties.creators.forEach((creator, index) => {
+          expect(metadata.additionalMetadata[`creator:${index}`]).to.equal(`${creator.address.toBase58()}:${creator.share}:0`);
+        });
 
         // Add more assertions as needed
       } catch (error) {
@@ -295,6 +370,8 @@ describe('Editions Controls Test Suite', () => {
         priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
         isPrivate: true,
         merkleRoot: allowListConfig.merkleRoot,
+        dutchAuction: null,
+        tokenGate: null,
       };
       const phaseIx = await editionsControlsProgram.methods
         .addPhase(phase0Config)
@@ -331,6 +408,8 @@ describe('Editions Controls Test Suite', () => {
         priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
         isPrivate: false,
         merkleRoot: allowListConfig.merkleRoot,
+        dutchAuction: null,
+        tokenGate: null,
       };
 
       const phaseIx = await editionsControlsProgram.methods
@@ -369,6 +448,8 @@ describe('Editions Controls Test Suite', () => {
         priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
         isPrivate: false,
         merkleRoot: null,
+        dutchAuction: null,
+        tokenGate: null,
       };
 
       const phaseIx = await editionsControlsProgram.methods
@@ -407,6 +488,8 @@ describe('Editions Controls Test Suite', () => {
         priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
         isPrivate: false,
         merkleRoot: null,
+        dutchAuction: null,
+        tokenGate: null,
       };
 
       const phaseIx = await editionsControlsProgram.methods
@@ -445,6 +528,8 @@ describe('Editions Controls Test Suite', () => {
         priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
         isPrivate: false,
         merkleRoot: null,
+        dutchAuction: null,
+        tokenGate: null,
       };
 
       const phaseIx = await editionsControlsProgram.methods
@@ -483,6 +568,8 @@ describe('Editions Controls Test Suite', () => {
         priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
         isPrivate: false,
         merkleRoot: null,
+        dutchAuction: null,
+        tokenGate: null,
       };
 
       const phaseIx = await editionsControlsProgram.methods
@@ -521,6 +608,8 @@ describe('Editions Controls Test Suite', () => {
         priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
         isPrivate: true,
         merkleRoot: null, // Invalid: null merkle root for private phase
+        dutchAuction: null,
+        tokenGate: null,
       };
 
       const phaseIx = await editionsControlsProgram.methods
@@ -561,6 +650,9 @@ describe('Editions Controls Test Suite', () => {
           merkleProof: allowListConfig.list[0].proof,
           allowListPrice: allowListConfig.list[0].price,
           allowListMaxClaims: allowListConfig.list[0].max_claims,
+          quantity: 1,
+          gateHashlistIndex: null,
+          cosign: null,
         };
 
         const mint = Keypair.generate();
@@ -578,6 +670,7 @@ describe('Editions Controls Test Suite', () => {
             editionsControls: editionsControlsPda,
             hashlist: hashlistPda,
             hashlistMarker: hashlistMarkerPda,
+            hashlistPage: hashlistPagePda,
             payer: minter0.publicKey,
             signer: minter0.publicKey,
             minter: minter0.publicKey,
@@ -587,7 +680,21 @@ describe('Editions Controls Test Suite', () => {
             member: member.publicKey,
             group: group.publicKey,
             groupMint: groupMint.publicKey,
-            platformFeeRecipient1: platformFeeAdmin.publicKey,
+            paymentMint: null,
+            payerPaymentAccount: null,
+            treasuryPaymentAccount: null,
+            paymentTokenProgram: null,
+            gateTokenAccount: null,
+            gateMember: null,
+            gateMarker: null,
+            gateMint: null,
+            gateTokenProgram: null,
+            gateDeployment: null,
+            gateHashlist: null,
+            gateHashlistMarker: null,
+            gateHashlistPage: null,
+            extraMetasAccount: null,
+            instructionsSysvar: null,
             tokenAccount: associatedTokenAddressSync,
             treasury: treasury.publicKey,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -596,6 +703,7 @@ describe('Editions Controls Test Suite', () => {
             systemProgram: SystemProgram.programId,
             raribleEditionsProgram: editionsProgram.programId,
           })
+          .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
           .instruction();
         const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
 
@@ -633,6 +741,13 @@ describe('Editions Controls Test Suite', () => {
         expect(minterStatsDecoded.data.mintCount.toString()).to.equal('1');
         expect(minterStatsPhaseDecoded.data.mintCount.toString()).to.equal('1');
 
+        firstMint = mint.publicKey;
+
+        // Verify that the mint was recorded in the first hashlist page
+        const hashlistEntries = await getHashlistEntries(provider.connection, editionsPda, editionsProgram);
+        expect(hashlistEntries.length).to.equal(1);
+        expect(hashlistEntries[0].mint.toBase58()).to.equal(mint.publicKey.toBase58());
+
         // When the fee is flat, is paid by the minter.
         const expectedPlatformFee = collectionConfig.platformFee.platformFeeValue;
         const expectedTreasuryIncome = mintConfig.allowListPrice;
@@ -663,6 +778,9 @@ describe('Editions Controls Test Suite', () => {
           merkleProof: null,
           allowListPrice: null,
           allowListMaxClaims: null,
+          quantity: 1,
+          gateHashlistIndex: null,
+          cosign: null,
         };
 
         const mint = Keypair.generate();
@@ -680,6 +798,7 @@ describe('Editions Controls Test Suite', () => {
             editionsControls: editionsControlsPda,
             hashlist: hashlistPda,
             hashlistMarker: hashlistMarkerPda,
+            hashlistPage: hashlistPagePda,
             payer: minter0.publicKey,
             signer: minter0.publicKey,
             minter: minter0.publicKey,
@@ -689,7 +808,21 @@ describe('Editions Controls Test Suite', () => {
             member: member.publicKey,
             group: group.publicKey,
             groupMint: groupMint.publicKey,
-            platformFeeRecipient1: platformFeeAdmin.publicKey,
+            paymentMint: null,
+            payerPaymentAccount: null,
+            treasuryPaymentAccount: null,
+            paymentTokenProgram: null,
+            gateTokenAccount: null,
+            gateMember: null,
+            gateMarker: null,
+            gateMint: null,
+            gateTokenProgram: null,
+            gateDeployment: null,
+            gateHashlist: null,
+            gateHashlistMarker: null,
+            gateHashlistPage: null,
+            extraMetasAccount: null,
+            instructionsSysvar: null,
             tokenAccount: associatedTokenAddressSync,
             treasury: treasury.publicKey,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -698,6 +831,7 @@ describe('Editions Controls Test Suite', () => {
             systemProgram: SystemProgram.programId,
             raribleEditionsProgram: editionsProgram.programId,
           })
+          .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
           .instruction();
 
         const transaction = new Transaction().add(mintIx);
@@ -715,6 +849,9 @@ describe('Editions Controls Test Suite', () => {
           merkleProof: allowListConfig.list[0].proof,
           allowListPrice: allowListConfig.list[0].price,
           allowListMaxClaims: allowListConfig.list[0].max_claims,
+          quantity: 1,
+          gateHashlistIndex: null,
+          cosign: null,
         };
 
         // mint twice, then the third mint should fail because the max claims for the allowlist is 3
@@ -734,6 +871,7 @@ describe('Editions Controls Test Suite', () => {
               editionsControls: editionsControlsPda,
               hashlist: hashlistPda,
               hashlistMarker: hashlistMarkerPda,
+              hashlistPage: hashlistPagePda,
               payer: minter0.publicKey,
               signer: minter0.publicKey,
               minter: minter0.publicKey,
@@ -743,7 +881,21 @@ describe('Editions Controls Test Suite', () => {
               member: member.publicKey,
               group: group.publicKey,
               groupMint: groupMint.publicKey,
-              platformFeeRecipient1: platformFeeAdmin.publicKey,
+              paymentMint: null,
+              payerPaymentAccount: null,
+              treasuryPaymentAccount: null,
+              paymentTokenProgram: null,
+              gateTokenAccount: null,
+              gateMember: null,
+              gateMarker: null,
+              gateMint: null,
+              gateTokenProgram: null,
+              gateDeployment: null,
+              gateHashlist: null,
+              gateHashlistMarker: null,
+              gateHashlistPage: null,
+              extraMetasAccount: null,
+              instructionsSysvar: null,
               tokenAccount: associatedTokenAddressSync,
               treasury: treasury.publicKey,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -752,6 +904,7 @@ describe('Editions Controls Test Suite', () => {
               systemProgram: SystemProgram.programId,
               raribleEditionsProgram: editionsProgram.programId,
             })
+            .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
             .instruction();
 
           const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -794,6 +947,9 @@ describe('Editions Controls Test Suite', () => {
           merkleProof: allowListConfig.list[1].proof,
           allowListPrice: allowListConfig.list[1].price,
           allowListMaxClaims: allowListConfig.list[1].max_claims,
+          quantity: 1,
+          gateHashlistIndex: null,
+          cosign: null,
         };
 
         const mint = Keypair.generate();
@@ -815,6 +971,7 @@ describe('Editions Controls Test Suite', () => {
             editionsControls: editionsControlsPda,
             hashlist: hashlistPda,
             hashlistMarker: hashlistMarkerPda,
+            hashlistPage: hashlistPagePda,
             payer: minter1.publicKey,
             signer: minter1.publicKey,
             minter: minter1.publicKey,
@@ -824,7 +981,21 @@ describe('Editions Controls Test Suite', () => {
             member: member.publicKey,
             group: group.publicKey,
             groupMint: groupMint.publicKey,
-            platformFeeRecipient1: platformFeeAdmin.publicKey,
+            paymentMint: null,
+            payerPaymentAccount: null,
+            treasuryPaymentAccount: null,
+            paymentTokenProgram: null,
+            gateTokenAccount: null,
+            gateMember: null,
+            gateMarker: null,
+            gateMint: null,
+            gateTokenProgram: null,
+            gateDeployment: null,
+            gateHashlist: null,
+            gateHashlistMarker: null,
+            gateHashlistPage: null,
+            extraMetasAccount: null,
+            instructionsSysvar: null,
             tokenAccount: associatedTokenAddressSync,
             treasury: treasury.publicKey,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -833,6 +1004,7 @@ describe('Editions Controls Test Suite', () => {
             systemProgram: SystemProgram.programId,
             raribleEditionsProgram: editionsProgram.programId,
           })
+          .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
           .instruction();
 
         const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -871,6 +1043,9 @@ describe('Editions Controls Test Suite', () => {
           merkleProof: null,
           allowListPrice: null,
           allowListMaxClaims: null,
+          quantity: 1,
+          gateHashlistIndex: null,
+          cosign: null,
         };
 
         const mint = Keypair.generate();
@@ -892,6 +1067,7 @@ describe('Editions Controls Test Suite', () => {
             editionsControls: editionsControlsPda,
             hashlist: hashlistPda,
             hashlistMarker: hashlistMarkerPda,
+            hashlistPage: hashlistPagePda,
             payer: minter1.publicKey,
             signer: minter1.publicKey,
             minter: minter1.publicKey,
@@ -901,7 +1077,21 @@ describe('Editions Controls Test Suite', () => {
             member: member.publicKey,
             group: group.publicKey,
             groupMint: groupMint.publicKey,
-            platformFeeRecipient1: platformFeeAdmin.publicKey,
+            paymentMint: null,
+            payerPaymentAccount: null,
+            treasuryPaymentAccount: null,
+            paymentTokenProgram: null,
+            gateTokenAccount: null,
+            gateMember: null,
+            gateMarker: null,
+            gateMint: null,
+            gateTokenProgram: null,
+            gateDeployment: null,
+            gateHashlist: null,
+            gateHashlistMarker: null,
+            gateHashlistPage: null,
+            extraMetasAccount: null,
+            instructionsSysvar: null,
             tokenAccount: associatedTokenAddressSync,
             treasury: treasury.publicKey,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -910,6 +1100,7 @@ describe('Editions Controls Test Suite', () => {
             systemProgram: SystemProgram.programId,
             raribleEditionsProgram: editionsProgram.programId,
           })
+          .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
           .instruction();
 
         const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -948,6 +1139,9 @@ describe('Editions Controls Test Suite', () => {
           merkleProof: null,
           allowListPrice: null,
           allowListMaxClaims: null,
+          quantity: 1,
+          gateHashlistIndex: null,
+          cosign: null,
         };
 
         const mintWithControls = async () => {
@@ -966,6 +1160,7 @@ describe('Editions Controls Test Suite', () => {
               editionsControls: editionsControlsPda,
               hashlist: hashlistPda,
               hashlistMarker: hashlistMarkerPda,
+              hashlistPage: hashlistPagePda,
               payer: minter1.publicKey,
               signer: minter1.publicKey,
               minter: minter1.publicKey,
@@ -975,7 +1170,21 @@ describe('Editions Controls Test Suite', () => {
               member: member.publicKey,
               group: group.publicKey,
               groupMint: groupMint.publicKey,
-              platformFeeRecipient1: platformFeeAdmin.publicKey,
+              paymentMint: null,
+              payerPaymentAccount: null,
+              treasuryPaymentAccount: null,
+              paymentTokenProgram: null,
+              gateTokenAccount: null,
+              gateMember: null,
+              gateMarker: null,
+              gateMint: null,
+              gateTokenProgram: null,
+              gateDeployment: null,
+              gateHashlist: null,
+              gateHashlistMarker: null,
+              gateHashlistPage: null,
+              extraMetasAccount: null,
+              instructionsSysvar: null,
               tokenAccount: associatedTokenAddressSync,
               treasury: treasury.publicKey,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -984,6 +1193,7 @@ describe('Editions Controls Test Suite', () => {
               systemProgram: SystemProgram.programId,
               raribleEditionsProgram: editionsProgram.programId,
             })
+            .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
             .instruction();
 
           const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -1021,6 +1231,9 @@ describe('Editions Controls Test Suite', () => {
             merkleProof: null,
             allowListPrice: null,
             allowListMaxClaims: null,
+            quantity: 1,
+            gateHashlistIndex: null,
+            cosign: null,
           };
 
           const hashlistMarkerPda = getHashlistMarkerPda(editionsPda, mint.publicKey, editionsProgram.programId);
@@ -1035,6 +1248,7 @@ describe('Editions Controls Test Suite', () => {
               editionsControls: editionsControlsPda,
               hashlist: hashlistPda,
               hashlistMarker: hashlistMarkerPda,
+              hashlistPage: hashlistPagePda,
               payer: minter.publicKey,
               signer: minter.publicKey,
               minter: minter.publicKey,
@@ -1044,7 +1258,21 @@ describe('Editions Controls Test Suite', () => {
               member: member.publicKey,
               group: group.publicKey,
               groupMint: groupMint.publicKey,
-              platformFeeRecipient1: platformFeeAdmin.publicKey,
+              paymentMint: null,
+              payerPaymentAccount: null,
+              treasuryPaymentAccount: null,
+              paymentTokenProgram: null,
+              gateTokenAccount: null,
+              gateMember: null,
+              gateMarker: null,
+              gateMint: null,
+              gateTokenProgram: null,
+              gateDeployment: null,
+              gateHashlist: null,
+              gateHashlistMarker: null,
+              gateHashlistPage: null,
+              extraMetasAccount: null,
+              instructionsSysvar: null,
               tokenAccount: associatedTokenAddressSync,
               treasury: treasury.publicKey,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -1053,6 +1281,7 @@ describe('Editions Controls Test Suite', () => {
               systemProgram: SystemProgram.programId,
               raribleEditionsProgram: editionsProgram.programId,
             })
+            .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
             .instruction();
 
           const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -1110,6 +1339,9 @@ describe('Editions Controls Test Suite', () => {
             merkleProof: null,
             allowListPrice: null,
             allowListMaxClaims: null,
+            quantity: 1,
+            gateHashlistIndex: null,
+            cosign: null,
           };
 
           const mint = Keypair.generate();
@@ -1131,6 +1363,7 @@ describe('Editions Controls Test Suite', () => {
               editionsControls: editionsControlsPda,
               hashlist: hashlistPda,
               hashlistMarker: hashlistMarkerPda,
+              hashlistPage: hashlistPagePda,
               payer: minter2.publicKey,
               signer: minter2.publicKey,
               minter: minter2.publicKey,
@@ -1140,7 +1373,21 @@ describe('Editions Controls Test Suite', () => {
               member: member.publicKey,
               group: group.publicKey,
               groupMint: groupMint.publicKey,
-              platformFeeRecipient1: platformFeeAdmin.publicKey,
+              paymentMint: null,
+              payerPaymentAccount: null,
+              treasuryPaymentAccount: null,
+              paymentTokenProgram: null,
+              gateTokenAccount: null,
+              gateMember: null,
+              gateMarker: null,
+              gateMint: null,
+              gateTokenProgram: null,
+              gateDeployment: null,
+              gateHashlist: null,
+              gateHashlistMarker: null,
+              gateHashlistPage: null,
+              extraMetasAccount: null,
+              instructionsSysvar: null,
               tokenAccount: associatedTokenAddressSync,
               treasury: treasury.publicKey,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -1149,6 +1396,7 @@ describe('Editions Controls Test Suite', () => {
               systemProgram: SystemProgram.programId,
               raribleEditionsProgram: editionsProgram.programId,
             })
+            .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
             .instruction();
 
           const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -1186,6 +1434,9 @@ describe('Editions Controls Test Suite', () => {
             merkleProof: allowListConfig.list[0].proof,
             allowListPrice: allowListConfig.list[0].price,
             allowListMaxClaims: allowListConfig.list[0].max_claims,
+            quantity: 1,
+            gateHashlistIndex: null,
+            cosign: null,
           };
 
           const mint = Keypair.generate();
@@ -1203,6 +1454,7 @@ describe('Editions Controls Test Suite', () => {
               editionsControls: editionsControlsPda,
               hashlist: hashlistPda,
               hashlistMarker: hashlistMarkerPda,
+              hashlistPage: hashlistPagePda,
               payer: minter2.publicKey,
               signer: minter2.publicKey,
               minter: minter2.publicKey,
@@ -1212,7 +1464,21 @@ describe('Editions Controls Test Suite', () => {
               member: member.publicKey,
               group: group.publicKey,
               groupMint: groupMint.publicKey,
-              platformFeeRecipient1: platformFeeAdmin.publicKey,
+              paymentMint: null,
+              payerPaymentAccount: null,
+              treasuryPaymentAccount: null,
+              paymentTokenProgram: null,
+              gateTokenAccount: null,
+              gateMember: null,
+              gateMarker: null,
+              gateMint: null,
+              gateTokenProgram: null,
+              gateDeployment: null,
+              gateHashlist: null,
+              gateHashlistMarker: null,
+              gateHashlistPage: null,
+              extraMetasAccount: null,
+              instructionsSysvar: null,
               tokenAccount: associatedTokenAddressSync,
               treasury: treasury.publicKey,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -1221,6 +1487,7 @@ describe('Editions Controls Test Suite', () => {
               systemProgram: SystemProgram.programId,
               raribleEditionsProgram: editionsProgram.programId,
             })
+            .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
             .instruction();
 
           const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -1265,6 +1532,9 @@ describe('Editions Controls Test Suite', () => {
               merkleProof: null,
               allowListPrice: null,
               allowListMaxClaims: null,
+              quantity: 1,
+              gateHashlistIndex: null,
+              cosign: null,
             };
 
             const hashlistMarkerPda = getHashlistMarkerPda(editionsPda, mint.publicKey, editionsProgram.programId);
@@ -1279,6 +1549,7 @@ describe('Editions Controls Test Suite', () => {
                 editionsControls: editionsControlsPda,
                 hashlist: hashlistPda,
                 hashlistMarker: hashlistMarkerPda,
+                hashlistPage: hashlistPagePda,
                 payer: minter.publicKey,
                 signer: minter.publicKey,
                 minter: minter.publicKey,
@@ -1288,7 +1559,21 @@ describe('Editions Controls Test Suite', () => {
                 member: member.publicKey,
                 group: group.publicKey,
                 groupMint: groupMint.publicKey,
-                platformFeeRecipient1: platformFeeAdmin.publicKey,
+                paymentMint: null,
+                payerPaymentAccount: null,
+                treasuryPaymentAccount: null,
+                paymentTokenProgram: null,
+                gateTokenAccount: null,
+                gateMember: null,
+                gateMarker: null,
+                gateMint: null,
+                gateTokenProgram: null,
+                gateDeployment: null,
+                gateHashlist: null,
+                gateHashlistMarker: null,
+                gateHashlistPage: null,
+                extraMetasAccount: null,
+                instructionsSysvar: null,
                 tokenAccount: associatedTokenAddressSync,
                 treasury: treasury.publicKey,
                 tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -1297,6 +1582,7 @@ describe('Editions Controls Test Suite', () => {
                 systemProgram: SystemProgram.programId,
                 raribleEditionsProgram: editionsProgram.programId,
               })
+              .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
               .instruction();
 
             const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -1346,6 +1632,10 @@ describe('Editions Controls Test Suite', () => {
             const errorString = JSON.stringify(error);
             expect(errorString).to.include('Minted out.');
           }
+
+          // the last mint moved the deployment to minted out
+          const editionsDecoded = await getEditions(provider.connection, editionsPda, editionsProgram);
+          expect(editionsDecoded.data.status).to.deep.equal({ mintedOut: {} });
         });
       });
 
@@ -1356,6 +1646,9 @@ describe('Editions Controls Test Suite', () => {
             merkleProof: null,
             allowListPrice: null,
             allowListMaxClaims: null,
+            quantity: 1,
+            gateHashlistIndex: null,
+            cosign: null,
           };
 
           const minter = Keypair.generate();
@@ -1378,6 +1671,7 @@ describe('Editions Controls Test Suite', () => {
               editionsControls: editionsControlsPda,
               hashlist: hashlistPda,
               hashlistMarker: hashlistMarkerPda,
+              hashlistPage: hashlistPagePda,
               payer: minter.publicKey,
               signer: minter.publicKey,
               minter: minter.publicKey,
@@ -1387,7 +1681,21 @@ describe('Editions Controls Test Suite', () => {
               member: member.publicKey,
               group: group.publicKey,
               groupMint: groupMint.publicKey,
-              platformFeeRecipient1: platformFeeAdmin.publicKey,
+              paymentMint: null,
+              payerPaymentAccount: null,
+              treasuryPaymentAccount: null,
+              paymentTokenProgram: null,
+              gateTokenAccount: null,
+              gateMember: null,
+              gateMarker: null,
+              gateMint: null,
+              gateTokenProgram: null,
+              gateDeployment: null,
+              gateHashlist: null,
+              gateHashlistMarker: null,
+              gateHashlistPage: null,
+              extraMetasAccount: null,
+              instructionsSysvar: null,
               tokenAccount: associatedTokenAddressSync,
               treasury: treasury.publicKey,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -1396,6 +1704,7 @@ describe('Editions Controls Test Suite', () => {
               systemProgram: SystemProgram.programId,
               raribleEditionsProgram: editionsProgram.programId,
             })
+            .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
             .instruction();
 
           const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -1413,6 +1722,9 @@ describe('Editions Controls Test Suite', () => {
             merkleProof: null,
             allowListPrice: null,
             allowListMaxClaims: null,
+            quantity: 1,
+            gateHashlistIndex: null,
+            cosign: null,
           };
 
           const minter = Keypair.generate();
@@ -1435,6 +1747,7 @@ describe('Editions Controls Test Suite', () => {
               editionsControls: editionsControlsPda,
               hashlist: hashlistPda,
               hashlistMarker: hashlistMarkerPda,
+              hashlistPage: hashlistPagePda,
               payer: minter.publicKey,
               signer: minter.publicKey,
               minter: minter.publicKey,
@@ -1444,7 +1757,21 @@ describe('Editions Controls Test Suite', () => {
               member: member.publicKey,
               group: group.publicKey,
               groupMint: groupMint.publicKey,
-              platformFeeRecipient1: platformFeeAdmin.publicKey,
+              paymentMint: null,
+              payerPaymentAccount: null,
+              treasuryPaymentAccount: null,
+              paymentTokenProgram: null,
+              gateTokenAccount: null,
+              gateMember: null,
+              gateMarker: null,
+              gateMint: null,
+              gateTokenProgram: null,
+              gateDeployment: null,
+              gateHashlist: null,
+              gateHashlistMarker: null,
+              gateHashlistPage: null,
+              extraMetasAccount: null,
+              instructionsSysvar: null,
               tokenAccount: associatedTokenAddressSync,
               treasury: treasury.publicKey,
               tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -1453,6 +1780,7 @@ describe('Editions Controls Test Suite', () => {
               systemProgram: SystemProgram.programId,
               raribleEditionsProgram: editionsProgram.programId,
             })
+            .remainingAccounts([{ pubkey: platformFeeAdmin.publicKey, isWritable: true, isSigner: false }])
             .instruction();
 
           const transaction = new Transaction().add(modifiedComputeUnits).add(mintIx);
@@ -1466,4 +1794,2603 @@ describe('Editions Controls Test Suite', () => {
       });
     });
   });
+
+  describe('Managing phases', () => {
+    it('Should pause and resume a phase [Phase Index 2]', async () => {
+      const pauseIx = await editionsControlsProgram.methods
+        .setPhaseActive({ phaseIndex: 2, active: false })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(pauseIx), [payer]);
+
+      let editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.phases[2].active).to.equal(false);
+
+      const resumeIx = await editionsControlsProgram.methods
+        .setPhaseActive({ phaseIndex: 2, active: true })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(resumeIx), [payer]);
+
+      editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.phases[2].active).to.equal(true);
+    });
+
+    it('Should update a phase [Phase Index 4]', async () => {
+      const updateIx = await editionsControlsProgram.methods
+        .updatePhase({
+          phaseIndex: 4,
+          priceAmount: new anchor.BN(1000000), // 0.1 SOL
+          startTime: phase4Config.startTime,
+          endTime: phase4Config.endTime,
+          maxMintsPerWallet: new anchor.BN(10),
+          maxMintsTotal: new anchor.BN(500),
+          merkleRoot: null,
+        })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(updateIx), [payer]);
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      if (VERBOSE_LOGGING) {
+        logEditionsControls(editionsControlsDecoded);
+      }
+      const phase = editionsControlsDecoded.data.phases[4];
+      expect(Number(phase.priceAmount)).to.equal(1000000);
+      expect(Number(phase.maxMintsPerWallet)).to.equal(10);
+      expect(Number(phase.maxMintsTotal)).to.equal(500);
+    });
+
+    it('Should fail to update a phase with an end time before its start time', async () => {
+      const updateIx = await editionsControlsProgram.methods
+        .updatePhase({
+          phaseIndex: 4,
+          priceAmount: phase4Config.priceAmount,
+          startTime: phase4Config.endTime,
+          endTime: phase4Config.startTime,
+          maxMintsPerWallet: phase4Config.maxMintsPerWallet,
+          maxMintsTotal: phase4Config.maxMintsTotal,
+          merkleRoot: null,
+        })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      try {
+        await provider.sendAndConfirm(new Transaction().add(updateIx), [payer]);
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('Phase end time must be after its start time');
+      }
+    });
+
+    it('Should fail to lower max mints total below the current mints of a phase [Phase Index 1]', async () => {
+      const updateIx = await editionsControlsProgram.methods
+        .updatePhase({
+          phaseIndex: 1,
+          priceAmount: phase1Config.priceAmount,
+          startTime: phase1Config.startTime,
+          endTime: phase1Config.endTime,
+          maxMintsPerWallet: phase1Config.maxMintsPerWallet,
+          maxMintsTotal: new anchor.BN(1),
+          merkleRoot: phase1Config.merkleRoot,
+        })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      try {
+        await provider.sendAndConfirm(new Transaction().add(updateIx), [payer]);
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('Max mints total cannot be lower than the current mints of the phase');
+      }
+    });
+
+    it('Should drop the last phase when it has no mints [Phase Index 5]', async () => {
+      const removeIx = await editionsControlsProgram.methods
+        .removePhase({ phaseIndex: 5 })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(removeIx), [payer]);
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.phases.length).to.equal(5);
+    });
+
+    it('Should tombstone a phase that already has mints [Phase Index 1]', async () => {
+      const removeIx = await editionsControlsProgram.methods
+        .removePhase({ phaseIndex: 1 })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(removeIx), [payer]);
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      // indices are preserved so that minter stats per phase stay valid
+      expect(editionsControlsDecoded.data.phases.length).to.equal(5);
+      expect(editionsControlsDecoded.data.phases[1].removed).to.equal(true);
+      expect(editionsControlsDecoded.data.phases[1].active).to.equal(false);
+
+      const reactivateIx = await editionsControlsProgram.methods
+        .setPhaseActive({ phaseIndex: 1, active: true })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      try {
+        await provider.sendAndConfirm(new Transaction().add(reactivateIx), [payer]);
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('Phase has been removed');
+      }
+    });
+  });
+
+  describe('Dutch auction phases', () => {
+    it('Should add a dutch auction phase with rebates [Phase Index 5]', async () => {
+      const dutchPhaseConfig: PhaseConfig = {
+        maxMintsPerWallet: new anchor.BN(100),
+        maxMintsTotal: new anchor.BN(1000),
+        priceAmount: new anchor.BN(1000000), // 0.1 SOL start price
+        startTime: new anchor.BN(new Date().getTime() / 1000),
+        endTime: new anchor.BN(new Date().getTime() / 1000 + 60 * 60 * 24), // 1 day from now
+        priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
+        isPrivate: false,
+        merkleRoot: null,
+        dutchAuction: {
+          floorPrice: new anchor.BN(200000), // 0.02 SOL
+          stepSeconds: new anchor.BN(60 * 60), // 1 hour
+          priceDecrement: new anchor.BN(100000), // 0.01 SOL per step
+          rebate: true,
+        },
+        tokenGate: null,
+      };
+
+      const phaseIx = await editionsControlsProgram.methods
+        .addPhase(dutchPhaseConfig)
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(phaseIx), [payer]);
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      const phase = editionsControlsDecoded.data.phases[5];
+      expect(phase.pricingMode).to.deep.equal({ dutchAuction: {} });
+      expect(Number(phase.floorPrice)).to.equal(200000);
+      expect(Number(phase.stepSeconds)).to.equal(60 * 60);
+      expect(Number(phase.priceDecrement)).to.equal(100000);
+      expect(phase.rebateEnabled).to.equal(true);
+    });
+
+    it('Should fail to add a dutch auction phase with a floor above the start price', async () => {
+      const invalidPhaseConfig: PhaseConfig = {
+        maxMintsPerWallet: new anchor.BN(100),
+        maxMintsTotal: new anchor.BN(1000),
+        priceAmount: new anchor.BN(100000),
+        startTime: new anchor.BN(new Date().getTime() / 1000),
+        endTime: new anchor.BN(new Date().getTime() / 1000 + 60 * 60 * 24),
+        priceToken: new PublicKey('So11111111111111111111111111111111111111112'),
+        isPrivate: false,
+        merkleRoot: null,
+        dutchAuction: {
+          floorPrice: new anchor.BN(200000), // Invalid: above the start price
+          stepSeconds: new anchor.BN(60 * 60),
+          priceDecrement: new anchor.BN(10000),
+          rebate: false,
+        },
+        tokenGate: null,
+      };
+
+      const phaseIx = await editionsControlsProgram.methods
+        .addPhase(invalidPhaseConfig)
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+          payer: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .signers([])
+        .instruction();
+
+      try {
+        await provider.sendAndConfirm(new Transaction().add(phaseIx), [payer]);
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('Invalid dutch auction configuration');
+      }
+    });
+
+    it('Should fail to lower the start price of a dutch auction phase below its floor', async () => {
+      const phase = (await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram)).data.phases[5];
+      const updateIx = await editionsControlsProgram.methods
+        .updatePhase({
+          phaseIndex: 5,
+          priceAmount: new anchor.BN(100000), // Invalid: below the floor of 200000
+          startTime: new anchor.BN(phase.startTime),
+          maxMintsPerWallet: new anchor.BN(phase.maxMintsPerWallet),
+          maxMintsTotal: new anchor.BN(phase.maxMintsTotal),
+          endTime: new anchor.BN(phase.endTime),
+          merkleRoot: null,
+        })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await expectError(provider.sendAndConfirm(new Transaction().add(updateIx), [payer]), 'Invalid dutch auction configuration');
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(Number(editionsControlsDecoded.data.phases[5].priceAmount)).to.equal(1000000);
+    });
+  });
+
+  describe('Co-signer', () => {
+    it('Should set and clear the backend co-signer', async () => {
+      const cosigner = Keypair.generate();
+
+      const setIx = await editionsControlsProgram.methods
+        .updateCosigner({ cosigner: cosigner.publicKey })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(setIx), [payer]);
+
+      let editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.cosignerProgramId.toBase58()).to.equal(cosigner.publicKey.toBase58());
+
+      const clearIx = await editionsControlsProgram.methods
+        .updateCosigner({ cosigner: null })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(clearIx), [payer]);
+
+      editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.cosignerProgramId.toBase58()).to.equal(SystemProgram.programId.toBase58());
+    });
+  });
+
+  describe('Deployment status', () => {
+    it('Should not let the creator pause a minted out deployment', async () => {
+      const pauseIx = await editionsControlsProgram.methods
+        .setDeploymentStatus({ status: { paused: {} } })
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .signers([])
+        .instruction();
+
+      try {
+        await provider.sendAndConfirm(new Transaction().add(pauseIx), [payer]);
+        // If we reach this point, the test should fail
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('Invalid deployment status transition');
+      }
+    });
+  });
+
+  describe('Updating max supply', () => {
+    const updateMaxSupplyIx = (maxNumberOfTokens: number) =>
+      editionsControlsProgram.methods
+        .updateMaxSupply({ maxNumberOfTokens: new anchor.BN(maxNumberOfTokens) })
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          editionsControls: editionsControlsPda,
+          creator: payer.publicKey,
+          group: group.publicKey,
+          groupExtensionProgram: TOKEN_GROUP_EXTENSION_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .signers([])
+        .instruction();
+
+    it('Should reopen a minted out deployment when the supply is raised, and mint it out again when lowered', async () => {
+      await provider.sendAndConfirm(new Transaction().add(await updateMaxSupplyIx(25)), [payer]);
+      let editionsDecoded = await getEditions(provider.connection, editionsPda, editionsProgram);
+      expect(editionsDecoded.data.maxNumberOfTokens.toString()).to.equal('25');
+      expect(editionsDecoded.data.status).to.deep.equal({ live: {} });
+
+      await provider.sendAndConfirm(new Transaction().add(await updateMaxSupplyIx(20)), [payer]);
+      editionsDecoded = await getEditions(provider.connection, editionsPda, editionsProgram);
+      expect(editionsDecoded.data.maxNumberOfTokens.toString()).to.equal('20');
+      expect(editionsDecoded.data.status).to.deep.equal({ mintedOut: {} });
+    });
+
+    it('Should not lower the supply below the number of tokens issued', async () => {
+      try {
+        await provider.sendAndConfirm(new Transaction().add(await updateMaxSupplyIx(10)), [payer]);
+        // If we reach this point, the test should fail
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('Max size cannot be reduced below current size');
+      }
+    });
+  });
+
+  describe('Creator handoff', () => {
+    it('Should hand the controls over to a new creator and back', async () => {
+      const newCreator = Keypair.generate();
+      const pendingCreatorPda = PublicKey.findProgramAddressSync(
+        [Buffer.from('pending_creator'), editionsControlsPda.toBuffer()],
+        editionsControlsProgram.programId
+      )[0];
+
+      const handoff = async (from: Keypair, to: Keypair) => {
+        const proposeIx = await editionsControlsProgram.methods
+          .proposeCreator({ newCreator: to.publicKey })
+          .accountsStrict({
+            editionsControls: editionsControlsPda,
+            pendingCreator: pendingCreatorPda,
+            payer: payer.publicKey,
+            creator: from.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .instruction();
+
+        const acceptIx = await editionsControlsProgram.methods
+          .acceptCreator()
+          .accountsStrict({
+            editionsControls: editionsControlsPda,
+            pendingCreator: pendingCreatorPda,
+            newCreator: to.publicKey,
+          })
+          .instruction();
+
+        await provider.sendAndConfirm(new Transaction().add(proposeIx).add(acceptIx), [payer, from, to]);
+      };
+
+      await handoff(payer, newCreator);
+      let editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.creator.toBase58()).to.equal(newCreator.publicKey.toBase58());
+
+      // the proposal is closed once accepted
+      expect(await provider.connection.getAccountInfo(pendingCreatorPda)).to.be.null;
+
+      await handoff(newCreator, payer);
+      editionsControlsDecoded = await getEditionsControls(provider.connection, editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.creator.toBase58()).to.equal(payer.publicKey.toBase58());
+    });
+
+    it('Should not let anyone but the pending creator accept', async () => {
+      const newCreator = Keypair.generate();
+      const impostor = Keypair.generate();
+      const pendingCreatorPda = PublicKey.findProgramAddressSync(
+        [Buffer.from('pending_creator'), editionsControlsPda.toBuffer()],
+        editionsControlsProgram.programId
+      )[0];
+
+      const proposeIx = await editionsControlsProgram.methods
+        .proposeCreator({ newCreator: newCreator.publicKey })
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          pendingCreator: pendingCreatorPda,
+          payer: payer.publicKey,
+          creator: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(proposeIx), [payer]);
+
+      const acceptIx = await editionsControlsProgram.methods
+        .acceptCreator()
+        .accountsStrict({
+          editionsControls: editionsControlsPda,
+          pendingCreator: pendingCreatorPda,
+          newCreator: impostor.publicKey,
+        })
+        .instruction();
+
+      try {
+        await provider.sendAndConfirm(new Transaction().add(acceptIx), [payer, impostor]);
+        // If we reach this point, the test should fail
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('Signer is not the pending creator');
+      }
+    });
+  });
+
+  describe('Updating collection metadata', () => {
+    it('Should update the collection name and uri', async () => {
+      const updateIx = await editionsControlsProgram.methods
+        .updateCollectionMetadata({
+          name: 'Renamed Collection',
+          symbol: null,
+          uri: 'https://example.com/renamed/collection.json',
+          itemBaseName: null,
+          itemBaseUri: null,
+        })
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          editionsControls: editionsControlsPda,
+          payer: payer.publicKey,
+          creator: payer.publicKey,
+          groupMint: groupMint.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(updateIx), [payer]);
+
+      const metadata = await getTokenMetadata(provider.connection, groupMint.publicKey);
+      expect(metadata.name).to.equal('Renamed Collection');
+      expect(metadata.uri).to.equal('https://example.com/renamed/collection.json');
+      expect(metadata.symbol).to.equal(collectionConfig.symbol);
+    });
+  });
+
+  describe('Updating item metadata', () => {
+    it('Should update the name, uri and attributes of a minted edition', async () => {
+      const hashlistMarkerPda = getHashlistMarkerPda(editionsPda, firstMint, editionsProgram.programId);
+
+      const updateIx = await editionsControlsProgram.methods
+        .updateItemMetadata({
+          name: 'Renamed #1',
+          uri: 'https://example.com/renamed/1',
+          additionalMetadata: [{ field: 'background', value: 'blue' }],
+        })
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          editionsControls: editionsControlsPda,
+          hashlistMarker: hashlistMarkerPda,
+          payer: payer.publicKey,
+          creator: payer.publicKey,
+          mint: firstMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .signers([])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(updateIx), [payer]);
+
+      const metadata = await getTokenMetadata(provider.connection, firstMint);
+      expect(metadata.name).to.equal('Renamed #1');
+      expect(metadata.uri).to.equal('https://example.com/renamed/1');
+      expect(metadata.additionalMetadata['background']).to.equal('blue');
+    });
+  });
+
+  describe('Verifying creators', () => {
+    it('Should let a listed creator verify itself and sync the flag to a minted edition', async () => {
+      const verifyIx = await editionsProgram.methods
+        .verifyCreator()
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          royaltyConfig: royaltyConfigPda,
+          payer: payer.publicKey,
+          creator: creator1.publicKey,
+          groupMint: groupMint.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+
+      const syncIx = await editionsProgram.methods
+        .syncRoyalties()
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          royaltyConfig: royaltyConfigPda,
+          hashlistMarker: getHashlistMarkerPda(editionsPda, firstMint, editionsProgram.programId),
+          payer: payer.publicKey,
+          mint: firstMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(verifyIx).add(syncIx), [payer, creator1]);
+
+      const royaltyConfig = await editionsProgram.account.royaltyConfig.fetch(royaltyConfigPda);
+      expect(royaltyConfig.creators[0].verified).to.be.true;
+      expect(royaltyConfig.creators[1].verified).to.be.false;
+
+      for (const mint of [groupMint.publicKey, firstMint]) {
+        const metadata = await getTokenMetadata(provider.connection, mint);
+        expect(metadata.additionalMetadata['creator:0']).to.equal(`${creator1.publicKey.toBase58()}:50:1`);
+        expect(metadata.additionalMetadata['creator:1']).to.equal(`${creator2.publicKey.toBase58()}:50:0`);
+      }
+    });
+
+    it('Should not let a wallet outside the creator list verify', async () => {
+      const outsider = Keypair.generate();
+      const verifyIx = await editionsProgram.methods
+        .verifyCreator()
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          royaltyConfig: royaltyConfigPda,
+          payer: payer.publicKey,
+          creator: outsider.publicKey,
+          groupMint: groupMint.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+
+      try {
+        await provider.sendAndConfirm(new Transaction().add(verifyIx), [payer, outsider]);
+        expect.fail('Transaction should have failed');
+      } catch (error) {
+        const errorString = JSON.stringify(error);
+        expect(errorString).to.include('The Address you provided is invalid');
+      }
+    });
+  });
+
+  describe('Burning editions', () => {
+    it('Should burn an edition and flag its hashlist entry', async () => {
+      const hashlistMarkerPda = getHashlistMarkerPda(editionsPda, firstMint, editionsProgram.programId);
+      const tokenAccount = getAssociatedTokenAddressSync(firstMint, minter0.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+      const burnIx = await editionsProgram.methods
+        .burn({ hashlistIndex: 0 })
+        .accountsStrict({
+          editionsDeployment: editionsPda,
+          hashlist: hashlistPda,
+          hashlistPage: hashlistPagePda,
+          hashlistMarker: hashlistMarkerPda,
+          owner: minter0.publicKey,
+          mint: firstMint,
+          tokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([minter0])
+        .instruction();
+
+      await provider.sendAndConfirm(new Transaction().add(burnIx), [minter0]);
+
+      const editionsDecoded = await getEditions(provider.connection, editionsPda, editionsProgram);
+      expect(editionsDecoded.data.numberOfTokensBurned.toString()).to.equal('1');
+
+      // the marker and the token account are closed
+      expect(await provider.connection.getAccountInfo(hashlistMarkerPda)).to.be.null;
+      expect(await provider.connection.getAccountInfo(tokenAccount)).to.be.null;
+
+      // the entry keeps its position with the burned flag set on its order
+      const hashlistEntries = await getHashlistEntries(provider.connection, editionsPda, editionsProgram);
+      expect(hashlistEntries[0].mint.toBase58()).to.equal(firstMint.toBase58());
+      expect(hashlistEntries[0].order.testn(63)).to.be.true;
+    });
+  });
+
+  describe('Paying in spl tokens', () => {
+    let deployment: TestDeployment;
+    let paymentMint: PublicKey;
+    let splMinter: Keypair;
+    let minterPaymentAccount: PublicKey;
+    let treasuryPaymentAccount: PublicKey;
+    let feeRecipientPaymentAccount: PublicKey;
+
+    before(async () => {
+      splMinter = Keypair.generate();
+      await airdrop(provider, splMinter.publicKey);
+
+      // 5% platform fee on a 1 token price
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        platformFee: {
+          platformFeeValue: new anchor.BN(500),
+          recipients: [{ address: platformFeeAdmin.publicKey, share: 100 }],
+          isFeeFlat: false,
+        },
+      });
+
+      paymentMint = await createMint(provider.connection, payer, payer.publicKey, null, 6);
+      minterPaymentAccount = await createAssociatedTokenAccount(provider.connection, payer, paymentMint, splMinter.publicKey);
+      treasuryPaymentAccount = await createAssociatedTokenAccount(provider.connection, payer, paymentMint, deployment.treasury);
+      feeRecipientPaymentAccount = await createAssociatedTokenAccount(provider.connection, payer, paymentMint, platformFeeAdmin.publicKey);
+      await mintTo(provider.connection, payer, paymentMint, minterPaymentAccount, payer, 10_000_000);
+
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig({
+        priceAmount: new anchor.BN(1_000_000),
+        priceToken: paymentMint,
+      }));
+    });
+
+    const splMintIx = (edition: EditionAccounts, accounts: Record<string, PublicKey | null> = {}) =>
+      mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: splMinter.publicKey,
+        editions: [edition],
+        accounts: {
+          paymentMint,
+          payerPaymentAccount: minterPaymentAccount,
+          treasuryPaymentAccount,
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+          ...accounts,
+        },
+        feeRecipients: [{ pubkey: feeRecipientPaymentAccount, isWritable: true, isSigner: false }],
+      });
+
+    it('Should split an spl price between the treasury and the platform fee recipient', async () => {
+      const edition = newEdition(deployment, editionsProgram.programId, splMinter.publicKey);
+      await send(provider, [await splMintIx(edition)], [splMinter, ...editionSigners([edition])]);
+
+      expect(Number((await getAccount(provider.connection, minterPaymentAccount)).amount)).to.equal(9_000_000);
+      expect(Number((await getAccount(provider.connection, treasuryPaymentAccount)).amount)).to.equal(950_000);
+      expect(Number((await getAccount(provider.connection, feeRecipientPaymentAccount)).amount)).to.equal(50_000);
+    });
+
+    it('Should fail to mint without the treasury payment account', async () => {
+      const edition = newEdition(deployment, editionsProgram.programId, splMinter.publicKey);
+      await expectError(
+        send(provider, [await splMintIx(edition, { treasuryPaymentAccount: null })], [splMinter, ...editionSigners([edition])]),
+        'Payment token accounts are required for phases not priced in native SOL'
+      );
+    });
+
+    it('Should fail to mint into a token account that is not owned by the treasury', async () => {
+      const otherPaymentAccount = await createAssociatedTokenAccount(provider.connection, payer, paymentMint, Keypair.generate().publicKey);
+      const edition = newEdition(deployment, editionsProgram.programId, splMinter.publicKey);
+      await expectError(
+        send(provider, [await splMintIx(edition, { treasuryPaymentAccount: otherPaymentAccount })], [splMinter, ...editionSigners([edition])]),
+        'Payment token account does not match the expected owner or mint'
+      );
+    });
+
+    it('Should fail to pay with a mint other than the phase price token', async () => {
+      const otherMint = await createMint(provider.connection, payer, payer.publicKey, null, 6);
+      const edition = newEdition(deployment, editionsProgram.programId, splMinter.publicKey);
+      await expectError(
+        send(provider, [await splMintIx(edition, { paymentMint: otherMint })], [splMinter, ...editionSigners([edition])]),
+        'Payment mint does not match the phase price token'
+      );
+    });
+
+    it('Should credit the full split of a transfer fee mint, with the payer covering the fee', async () => {
+      // 1% transfer fee on a token-2022 price mint
+      const feeMint = Keypair.generate();
+      const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+      await send(provider, [
+        SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: feeMint.publicKey,
+          space: mintLen,
+          lamports: await provider.connection.getMinimumBalanceForRentExemption(mintLen),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(feeMint.publicKey, payer.publicKey, payer.publicKey, 100, BigInt(1_000_000), TOKEN_2022_PROGRAM_ID),
+        createInitializeMintInstruction(feeMint.publicKey, 6, payer.publicKey, null, TOKEN_2022_PROGRAM_ID),
+      ], [payer, feeMint]);
+
+      const feeDeployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        platformFee: {
+          platformFeeValue: new anchor.BN(500),
+          recipients: [{ address: platformFeeAdmin.publicKey, share: 100 }],
+          isFeeFlat: false,
+        },
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, feeDeployment, phaseConfig({
+        priceAmount: new anchor.BN(1_000_000),
+        priceToken: feeMint.publicKey,
+      }));
+
+      const createFeeMintAccount = (owner: PublicKey) =>
+        createAssociatedTokenAccount(provider.connection, payer, feeMint.publicKey, owner, undefined, TOKEN_2022_PROGRAM_ID);
+      const getFeeMintAccount = (address: PublicKey) => getAccount(provider.connection, address, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      const minterAccount = await createFeeMintAccount(splMinter.publicKey);
+      const treasuryAccount = await createFeeMintAccount(feeDeployment.treasury);
+      const feeRecipientAccount = await createFeeMintAccount(platformFeeAdmin.publicKey);
+      await mintTo(provider.connection, payer, feeMint.publicKey, minterAccount, payer, 10_000_000, [], undefined, TOKEN_2022_PROGRAM_ID);
+
+      const edition = newEdition(feeDeployment, editionsProgram.programId, splMinter.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, feeDeployment, {
+        minter: splMinter.publicKey,
+        editions: [edition],
+        accounts: {
+          paymentMint: feeMint.publicKey,
+          payerPaymentAccount: minterAccount,
+          treasuryPaymentAccount: treasuryAccount,
+          paymentTokenProgram: TOKEN_2022_PROGRAM_ID,
+        },
+        feeRecipients: [{ pubkey: feeRecipientAccount, isWritable: true, isSigner: false }],
+      });
+      await send(provider, [mintIx], [splMinter, ...editionSigners([edition])]);
+
+      const treasuryState = await getFeeMintAccount(treasuryAccount);
+      const feeRecipientState = await getFeeMintAccount(feeRecipientAccount);
+      expect(Number(treasuryState.amount)).to.equal(950_000);
+      expect(Number(feeRecipientState.amount)).to.equal(50_000);
+
+      const withheld = getTransferFeeAmount(treasuryState)!.withheldAmount + getTransferFeeAmount(feeRecipientState)!.withheldAmount;
+      expect(withheld > BigInt(0)).to.be.true;
+      expect((await getFeeMintAccount(minterAccount)).amount).to.equal(BigInt(10_000_000 - 1_000_000) - withheld);
+    });
+  });
+
+  describe('Dutch auction rebates', () => {
+    let deployment: TestDeployment;
+    let earlyMinter: Keypair;
+    let lateMinter: Keypair;
+    const startPrice = 1_000_000;
+    const priceDecrement = 100_000;
+    const stepSeconds = 60 * 60;
+
+    const updatePhaseIx = (startTime: number, endTime: number, maxMintsTotal = 1000) =>
+      editionsControlsProgram.methods
+        .updatePhase({
+          phaseIndex: 0,
+          priceAmount: new anchor.BN(startPrice),
+          startTime: new anchor.BN(startTime),
+          maxMintsPerWallet: new anchor.BN(100),
+          maxMintsTotal: new anchor.BN(maxMintsTotal),
+          endTime: new anchor.BN(endTime),
+          merkleRoot: null,
+        })
+        .accountsStrict({
+          editionsControls: deployment.editionsControlsPda,
+          creator: payer.publicKey,
+        })
+        .instruction();
+
+    const claimRebateIx = (minter: PublicKey) =>
+      editionsControlsProgram.methods
+        .claimRebate({ phaseIndex: 0 })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          minterStatsPhase: getMinterStatsPhasePda(deployment.editionsPda, minter, 0, editionsControlsProgram.programId),
+          minter,
+          treasury: deployment.treasury,
+        })
+        .instruction();
+
+    const auctionMint = async (minter: Keypair) => {
+      const edition = newEdition(deployment, editionsProgram.programId, minter.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: minter.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [minter, ...editionSigners([edition])]);
+    };
+
+    before(async () => {
+      earlyMinter = Keypair.generate();
+      lateMinter = Keypair.generate();
+      await airdrop(provider, earlyMinter.publicKey);
+      await airdrop(provider, lateMinter.publicKey);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram);
+
+      // started three and a half steps ago, so the price has dropped three times
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig({
+        priceAmount: new anchor.BN(startPrice),
+        startTime: new anchor.BN(now() - 3.5 * stepSeconds),
+        dutchAuction: {
+          floorPrice: new anchor.BN(200_000),
+          stepSeconds: new anchor.BN(stepSeconds),
+          priceDecrement: new anchor.BN(priceDecrement),
+          rebate: true,
+        },
+      }));
+    });
+
+    it('Should mint at the decayed price and escrow the proceeds', async () => {
+      await auctionMint(earlyMinter);
+
+      const expectedPrice = startPrice - 3 * priceDecrement;
+      const minterStatsPhase = await getMinterStats(
+        provider.connection,
+        getMinterStatsPhasePda(deployment.editionsPda, earlyMinter.publicKey, 0, editionsControlsProgram.programId),
+        editionsControlsProgram
+      );
+      expect(Number(minterStatsPhase.data.amountPaid)).to.equal(expectedPrice);
+      expect(Number(minterStatsPhase.data.escrowedAmount)).to.equal(expectedPrice);
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, deployment.editionsControlsPda, editionsControlsProgram);
+      expect(Number(editionsControlsDecoded.data.phases[0].clearingPrice)).to.equal(expectedPrice);
+    });
+
+    it('Should not pay out a rebate while the auction is running', async () => {
+      await expectError(send(provider, [await claimRebateIx(earlyMinter.publicKey)], [payer]), 'Rebates can only be claimed once the phase has ended or sold out');
+    });
+
+    it('Should lower the clearing price with a later, cheaper mint', async () => {
+      // moving the start back two more steps drops the price twice more
+      await send(provider, [await updatePhaseIx(now() - 5.5 * stepSeconds, now() + 60 * 60 * 24)], [payer]);
+      await auctionMint(lateMinter);
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, deployment.editionsControlsPda, editionsControlsProgram);
+      expect(Number(editionsControlsDecoded.data.phases[0].clearingPrice)).to.equal(startPrice - 5 * priceDecrement);
+    });
+
+    it('Should pay the overpayment back to the early minter once the auction has ended', async () => {
+      const startTime = now() - 5.5 * stepSeconds;
+      await send(provider, [await updatePhaseIx(startTime, now() - 60)], [payer]);
+
+      const minterBalanceBefore = await provider.connection.getBalance(earlyMinter.publicKey);
+      const treasuryBalanceBefore = await provider.connection.getBalance(deployment.treasury);
+
+      await send(provider, [await claimRebateIx(earlyMinter.publicKey)], [payer]);
+
+      const minterBalanceAfter = await provider.connection.getBalance(earlyMinter.publicKey);
+      const treasuryBalanceAfter = await provider.connection.getBalance(deployment.treasury);
+
+      // paid 700000, cleared at 500000
+      expect(minterBalanceAfter - minterBalanceBefore).to.equal(2 * priceDecrement);
+      expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(startPrice - 5 * priceDecrement);
+
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, deployment.editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.phases[0].rebateClaimed).to.be.true;
+
+      await expectError(send(provider, [await claimRebateIx(earlyMinter.publicKey)], [payer]), 'Nothing to claim');
+    });
+
+    it('Should not reopen or resize the auction once a rebate was claimed', async () => {
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, deployment.editionsControlsPda, editionsControlsProgram);
+      const phase = editionsControlsDecoded.data.phases[0];
+
+      await expectError(
+        send(provider, [await updatePhaseIx(Number(phase.startTime), now() + 60 * 60 * 24)], [payer]),
+        'End time and max mints total of a rebate auction cannot change'
+      );
+      await expectError(
+        send(provider, [await updatePhaseIx(Number(phase.startTime), Number(phase.endTime), 2)], [payer]),
+        'End time and max mints total of a rebate auction cannot change'
+      );
+    });
+  });
+
+  describe('Minting several editions at once', () => {
+    let deployment: TestDeployment;
+    let minterA: Keypair;
+    let minterB: Keypair;
+    const price = 100_000;
+    const flatFee = 10_000;
+
+    const mintQuantity = async (minter: Keypair, quantity: number, input = {}) => {
+      const editions = [...Array(quantity)].map(() => newEdition(deployment, editionsProgram.programId, minter.publicKey));
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: minter.publicKey,
+        editions,
+        input,
+      });
+      return send(provider, [mintIx], [minter, ...editionSigners(editions)]);
+    };
+
+    before(async () => {
+      minterA = Keypair.generate();
+      minterB = Keypair.generate();
+      await airdrop(provider, minterA.publicKey);
+      await airdrop(provider, minterB.publicKey);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        platformFee: {
+          platformFeeValue: new anchor.BN(flatFee),
+          recipients: [{ address: platformFeeAdmin.publicKey, share: 100 }],
+          isFeeFlat: true,
+        },
+      });
+      deployment.platformFeeRecipient = platformFeeAdmin.publicKey;
+
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig({
+        priceAmount: new anchor.BN(price),
+        maxMintsPerWallet: new anchor.BN(3),
+        maxMintsTotal: new anchor.BN(4),
+      }));
+    });
+
+    it('Should mint two editions and charge the price and the flat fee for each', async () => {
+      const treasuryBalanceBefore = await provider.connection.getBalance(deployment.treasury);
+      const feeRecipientBalanceBefore = await provider.connection.getBalance(platformFeeAdmin.publicKey);
+
+      await mintQuantity(minterA, 2);
+
+      expect((await provider.connection.getBalance(deployment.treasury)) - treasuryBalanceBefore).to.equal(2 * price);
+      expect((await provider.connection.getBalance(platformFeeAdmin.publicKey)) - feeRecipientBalanceBefore).to.equal(2 * flatFee);
+
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(editionsDecoded.data.numberOfTokensIssued.toString()).to.equal('2');
+      const minterStatsDecoded = await getMinterStats(
+        provider.connection,
+        getMinterStatsPda(deployment.editionsPda, minterA.publicKey, editionsControlsProgram.programId),
+        editionsControlsProgram
+      );
+      expect(minterStatsDecoded.data.mintCount.toString()).to.equal('2');
+      const editionsControlsDecoded = await getEditionsControls(provider.connection, deployment.editionsControlsPda, editionsControlsProgram);
+      expect(editionsControlsDecoded.data.phases[0].currentMints.toString()).to.equal('2');
+    });
+
+    it('Should count the whole quantity against the phase max per wallet', async () => {
+      await expectError(mintQuantity(minterA, 2), 'Exceeded wallet max mints for this phase');
+    });
+
+    it('Should count the whole quantity against the phase max mints', async () => {
+      await expectError(mintQuantity(minterB, 3), 'Exceeded max mints for this phase');
+    });
+
+    it('Should fail when the remaining accounts do not cover the quantity', async () => {
+      // accounts for one edition, quantity of two
+      await expectError(mintQuantity(minterB, 1, { quantity: 2 }), 'Mint, member, hashlist marker, token account, hashlist page');
+    });
+
+    it('Should fail to mint a quantity of zero', async () => {
+      await expectError(mintQuantity(minterB, 1, { quantity: 0 }), 'Quantity must be at least 1');
+    });
+  });
+
+  describe('Token gated phases', () => {
+    let deployment: TestDeployment;
+    let gateDeployment: TestDeployment;
+    let gateEdition: EditionAccounts;
+    let gatedMinter: Keypair;
+    let balanceMint: PublicKey;
+    let balanceAccount: PublicKey;
+
+    const gatedMintIx = async (minter: Keypair, phaseIndex: number, accounts: Record<string, PublicKey | null>) => {
+      const edition = newEdition(deployment, editionsProgram.programId, minter.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: minter.publicKey,
+        phaseIndex,
+        editions: [edition],
+        accounts,
+      });
+      return { mintIx, signers: [minter, ...editionSigners([edition])] };
+    };
+
+    const groupMemberAccounts = () => ({
+      gateTokenAccount: gateEdition.tokenAccount,
+      gateMember: gateEdition.member.publicKey,
+      gateMarker: getGateMarkerPda(deployment.editionsControlsPda, 0, gateEdition.mint.publicKey, editionsControlsProgram.programId),
+    });
+
+    before(async () => {
+      gatedMinter = Keypair.generate();
+      await airdrop(provider, gatedMinter.publicKey);
+
+      // the gating nft is an edition of another deployment
+      gateDeployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram);
+      await addPhase(provider, editionsControlsProgram, editionsProgram, gateDeployment, phaseConfig());
+      gateEdition = newEdition(gateDeployment, editionsProgram.programId, gatedMinter.publicKey);
+      const gateMintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, gateDeployment, {
+        minter: gatedMinter.publicKey,
+        editions: [gateEdition],
+      });
+      await send(provider, [gateMintIx], [gatedMinter, ...editionSigners([gateEdition])]);
+
+      balanceMint = await createMint(provider.connection, payer, payer.publicKey, null, 0);
+      balanceAccount = await createAssociatedTokenAccount(provider.connection, payer, balanceMint, gatedMinter.publicKey);
+      await mintTo(provider.connection, payer, balanceMint, balanceAccount, payer, 5);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram);
+      // [Phase Index 0] holders of the gate deployment, once per nft
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig({
+        tokenGate: {
+          gateType: { groupMember: {} },
+          gateKey: gateDeployment.group,
+          minBalance: new anchor.BN(0),
+          singleUse: true,
+        },
+      }));
+      // [Phase Index 1] holders of at least 10 balance tokens
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig({
+        tokenGate: {
+          gateType: { tokenBalance: {} },
+          gateKey: balanceMint,
+          minBalance: new anchor.BN(10),
+          singleUse: false,
+        },
+      }));
+    });
+
+    it('Should fail to mint on a gated phase without the gate accounts', async () => {
+      const { mintIx, signers } = await gatedMintIx(gatedMinter, 0, {});
+      await expectError(send(provider, [mintIx], signers), 'Token gate accounts are required for this phase');
+    });
+
+    it('Should not accept a gating nft held by another wallet', async () => {
+      const otherMinter = Keypair.generate();
+      await airdrop(provider, otherMinter.publicKey);
+      const { mintIx, signers } = await gatedMintIx(otherMinter, 0, groupMemberAccounts());
+      await expectError(send(provider, [mintIx], signers), 'Minter does not hold the token required by this phase');
+    });
+
+    it('Should mint with a member of the gating group, even with the marker address funded up front', async () => {
+      const { gateMarker } = groupMemberAccounts();
+      await send(provider, [SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: gateMarker, lamports: 1_000_000 })], [payer]);
+
+      const { mintIx, signers } = await gatedMintIx(gatedMinter, 0, groupMemberAccounts());
+      await send(provider, [mintIx], signers);
+
+      const marker = await editionsControlsProgram.account.gateMarker.fetch(gateMarker);
+      expect(marker.gateMint.toBase58()).to.equal(gateEdition.mint.publicKey.toBase58());
+      expect(marker.minter.toBase58()).to.equal(gatedMinter.publicKey.toBase58());
+    });
+
+    it('Should not let the same gating nft mint twice on a single use phase', async () => {
+      const { mintIx, signers } = await gatedMintIx(gatedMinter, 0, groupMemberAccounts());
+      await expectError(send(provider, [mintIx], signers), 'This gating token has already been used in this phase');
+    });
+
+    it('Should require the minimum balance on a token balance phase', async () => {
+      let { mintIx, signers } = await gatedMintIx(gatedMinter, 1, { gateTokenAccount: balanceAccount });
+      await expectError(send(provider, [mintIx], signers), 'Minter does not hold the token required by this phase');
+
+      await mintTo(provider.connection, payer, balanceMint, balanceAccount, payer, 5);
+      ({ mintIx, signers } = await gatedMintIx(gatedMinter, 1, { gateTokenAccount: balanceAccount }));
+      await send(provider, [mintIx], signers);
+
+      // the balance is only checked, not spent
+      expect(Number((await getAccount(provider.connection, balanceAccount)).amount)).to.equal(10);
+    });
+  });
+
+  describe('Burn-to-mint phases', () => {
+    let deployment: TestDeployment;
+    let gateDeployment: TestDeployment;
+    let gateEdition: EditionAccounts;
+    let burner: Keypair;
+    let burnMint: PublicKey;
+    let burnAccount: PublicKey;
+
+    const burnMintIx = async (phaseIndex: number, quantity: number, accounts: Record<string, PublicKey | null>, input = {}) => {
+      const editions = [...Array(quantity)].map(() => newEdition(deployment, editionsProgram.programId, burner.publicKey));
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: burner.publicKey,
+        phaseIndex,
+        editions,
+        accounts,
+        input,
+      });
+      return { mintIx, signers: [burner, ...editionSigners(editions)] };
+    };
+
+    before(async () => {
+      burner = Keypair.generate();
+      await airdrop(provider, burner.publicKey);
+
+      gateDeployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram);
+      await addPhase(provider, editionsControlsProgram, editionsProgram, gateDeployment, phaseConfig());
+      gateEdition = newEdition(gateDeployment, editionsProgram.programId, burner.publicKey);
+      const gateMintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, gateDeployment, {
+        minter: burner.publicKey,
+        editions: [gateEdition],
+      });
+      await send(provider, [gateMintIx], [burner, ...editionSigners([gateEdition])]);
+
+      burnMint = await createMint(provider.connection, payer, payer.publicKey, null, 0);
+      burnAccount = await createAssociatedTokenAccount(provider.connection, payer, burnMint, burner.publicKey);
+      await mintTo(provider.connection, payer, burnMint, burnAccount, payer, 5);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram);
+      // [Phase Index 0] burn an edition of the gate deployment
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig({
+        tokenGate: {
+          gateType: { burnEdition: {} },
+          gateKey: gateDeployment.editionsPda,
+          minBalance: new anchor.BN(0),
+          singleUse: false,
+        },
+      }));
+      // [Phase Index 1] burn 2 tokens per edition
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig({
+        tokenGate: {
+          gateType: { burnToken: {} },
+          gateKey: burnMint,
+          minBalance: new anchor.BN(2),
+          singleUse: false,
+        },
+      }));
+    });
+
+    it('Should burn an edition of the gate deployment to mint', async () => {
+      const { mintIx, signers } = await burnMintIx(0, 1, {
+        gateTokenAccount: gateEdition.tokenAccount,
+        gateMint: gateEdition.mint.publicKey,
+        gateDeployment: gateDeployment.editionsPda,
+        gateHashlist: gateDeployment.hashlistPda,
+        gateHashlistMarker: gateEdition.hashlistMarker,
+        gateHashlistPage: gateEdition.hashlistPage,
+      }, { gateHashlistIndex: 0 });
+      await send(provider, [mintIx], signers);
+
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(editionsDecoded.data.numberOfTokensIssued.toString()).to.equal('1');
+
+      // the burned edition is flagged in the gate deployment hashlist and its marker is closed
+      const gateEditionsDecoded = await getEditions(provider.connection, gateDeployment.editionsPda, editionsProgram);
+      expect(gateEditionsDecoded.data.numberOfTokensBurned.toString()).to.equal('1');
+      expect(await provider.connection.getAccountInfo(gateEdition.hashlistMarker)).to.be.null;
+      expect(await provider.connection.getAccountInfo(gateEdition.tokenAccount)).to.be.null;
+      const hashlistEntries = await getHashlistEntries(provider.connection, gateDeployment.editionsPda, editionsProgram);
+      expect(hashlistEntries[0].mint.toBase58()).to.equal(gateEdition.mint.publicKey.toBase58());
+      expect(hashlistEntries[0].order.testn(63)).to.be.true;
+    });
+
+    it('Should burn the gate balance for every edition minted', async () => {
+      const accounts = { gateTokenAccount: burnAccount, gateMint: burnMint, gateTokenProgram: TOKEN_PROGRAM_ID };
+
+      // 2 editions need 4 tokens
+      let { mintIx, signers } = await burnMintIx(1, 2, accounts);
+      await send(provider, [mintIx], signers);
+      expect(Number((await getAccount(provider.connection, burnAccount)).amount)).to.equal(1);
+
+      ({ mintIx, signers } = await burnMintIx(1, 1, accounts));
+      await expectError(send(provider, [mintIx], signers), 'Minter does not hold the token required by this phase');
+    });
+  });
+
+
+  describe('Co-signed mints', () => {
+    let deployment: TestDeployment;
+    let cosigner: Keypair;
+    let cosignedMinter: Keypair;
+
+    // editions_controls | minter | phase_index | expiry | nonce
+    const cosignIx = (signer: Keypair, minter: PublicKey, expiry: number, nonce: number) =>
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: signer.secretKey,
+        message: Buffer.concat([
+          deployment.editionsControlsPda.toBuffer(),
+          minter.toBuffer(),
+          new anchor.BN(0).toArrayLike(Buffer, 'le', 4),
+          new anchor.BN(expiry).toArrayLike(Buffer, 'le', 8),
+          new anchor.BN(nonce).toArrayLike(Buffer, 'le', 8),
+        ]),
+      });
+
+    const cosignedMint = async (signer: Keypair | null, expiry: number, nonce: number) => {
+      const edition = newEdition(deployment, editionsProgram.programId, cosignedMinter.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: cosignedMinter.publicKey,
+        editions: [edition],
+        input: { cosign: { expiry: new anchor.BN(expiry), nonce: new anchor.BN(nonce) } },
+        accounts: { instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY },
+      });
+      const instructions = signer ? [cosignIx(signer, cosignedMinter.publicKey, expiry, nonce), mintIx] : [mintIx];
+      return send(provider, instructions, [cosignedMinter, ...editionSigners([edition])]);
+    };
+
+    before(async () => {
+      cosigner = Keypair.generate();
+      cosignedMinter = Keypair.generate();
+      await airdrop(provider, cosignedMinter.publicKey);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        cosignerProgramId: cosigner.publicKey,
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+    });
+
+    it('Should fail to mint without a co-signature', async () => {
+      await expectError(cosignedMint(null, now() + 60 * 60, 1), 'A co-signature is required to mint');
+    });
+
+    it('Should let any wallet mint with a valid co-signature', async () => {
+      await cosignedMint(cosigner, now() + 60 * 60, 1);
+
+      const minterStatsDecoded = await getMinterStats(
+        provider.connection,
+        getMinterStatsPda(deployment.editionsPda, cosignedMinter.publicKey, editionsControlsProgram.programId),
+        editionsControlsProgram
+      );
+      expect(minterStatsDecoded.data.mintCount.toString()).to.equal('1');
+      expect(minterStatsDecoded.data.lastCosignNonce.toString()).to.equal('1');
+    });
+
+    it('Should not accept a co-signature replayed with a used nonce', async () => {
+      await expectError(cosignedMint(cosigner, now() + 60 * 60, 1), 'Co-signature nonce has already been used');
+    });
+
+    it('Should not accept a co-signature from another key', async () => {
+      await expectError(cosignedMint(Keypair.generate(), now() + 60 * 60, 2), 'Invalid co-signature');
+    });
+
+    it('Should not accept an expired co-signature', async () => {
+      await expectError(cosignedMint(cosigner, now() - 60 * 60, 2), 'Co-signature has expired');
+    });
+  });
+
+
+  describe('Delayed reveal', () => {
+    let deployment: TestDeployment;
+    let revealMinter: Keypair;
+    let hiddenMint: PublicKey;
+    const revealedUri = 'ipfs://revealed/{}';
+    const maxNumberOfTokens = 10;
+
+    const revealIx = (itemBaseUri: string) =>
+      editionsControlsProgram.methods
+        .reveal({ itemBaseUri })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          creator: payer.publicKey,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .instruction();
+
+    const drawRevealOffsetIx = () =>
+      editionsProgram.methods
+        .drawRevealOffset()
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        })
+        .instruction();
+
+    const mintOne = async () => {
+      const edition = newEdition(deployment, editionsProgram.programId, revealMinter.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: revealMinter.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [revealMinter, ...editionSigners([edition])]);
+      return edition.mint.publicKey;
+    };
+
+    before(async () => {
+      revealMinter = Keypair.generate();
+      await airdrop(provider, revealMinter.publicKey);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        maxNumberOfTokens: new anchor.BN(maxNumberOfTokens),
+        itemBaseUri: 'ipfs://hidden/placeholder.json',
+        provenanceHash: [...createHash('sha256').update(revealedUri).digest()],
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+
+      hiddenMint = await mintOne();
+    });
+
+    it('Should mint with the placeholder uri before the reveal', async () => {
+      const metadata = await getTokenMetadata(provider.connection, hiddenMint);
+      expect(metadata.uri).to.equal('ipfs://hidden/placeholder.json');
+    });
+
+    it('Should not change the item uri of a delayed reveal deployment', async () => {
+      const updateIx = await editionsControlsProgram.methods
+        .updateCollectionMetadata({
+          name: null,
+          symbol: null,
+          uri: null,
+          itemBaseName: null,
+          itemBaseUri: 'ipfs://other/{}',
+        })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          payer: payer.publicKey,
+          creator: payer.publicKey,
+          groupMint: deployment.groupMint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .instruction();
+      await expectError(send(provider, [updateIx], [payer]), 'Item uri of a delayed reveal deployment is fixed by its provenance hash');
+    });
+
+    it('Should not reveal a uri that does not match the provenance hash', async () => {
+      await expectError(send(provider, [await revealIx('ipfs://other/{}')], [payer]), 'Revealed uri does not match the provenance hash');
+    });
+
+    it('Should not draw the reveal offset in the transaction of the reveal', async () => {
+      await expectError(
+        send(provider, [await revealIx(revealedUri), await drawRevealOffsetIx()], [payer]),
+        'Reveal offset is drawn from a slot after the reveal'
+      );
+    });
+
+    it('Should pause mints until the reveal offset is drawn', async () => {
+      await send(provider, [await revealIx(revealedUri)], [payer]);
+
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(editionsDecoded.data.isRevealed).to.be.false;
+      expect(editionsDecoded.data.itemBaseUri).to.equal(revealedUri);
+      await expectError(mintOne(), 'Minting resumes once the reveal offset has been drawn');
+    });
+
+    it('Should draw the reveal offset and offset the numbers of new mints', async () => {
+      await send(provider, [await drawRevealOffsetIx()], [payer]);
+
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(editionsDecoded.data.isRevealed).to.be.true;
+      const offset = editionsDecoded.data.revealOffset;
+      expect(offset).to.be.lessThan(maxNumberOfTokens);
+
+      // the second mint gets the item number shifted by the reveal offset
+      const revealedMint = await mintOne();
+      const metadata = await getTokenMetadata(provider.connection, revealedMint);
+      expect(metadata.uri).to.equal(`ipfs://revealed/${((1 + offset) % maxNumberOfTokens) + 1}`);
+    });
+
+    it('Should rewrite the uri of items minted before the reveal', async () => {
+      const revealItemsIx = await editionsProgram.methods
+        .revealItems({ hashlistIndices: [0] })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          hashlist: deployment.hashlistPda,
+          hashlistPage: getHashlistPagePda(deployment.editionsPda, 0, editionsProgram.programId),
+          payer: payer.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([{ pubkey: hiddenMint, isWritable: true, isSigner: false }])
+        .instruction();
+      await send(provider, [revealItemsIx], [payer]);
+
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      const metadata = await getTokenMetadata(provider.connection, hiddenMint);
+      expect(metadata.uri).to.equal(`ipfs://revealed/${(editionsDecoded.data.revealOffset % maxNumberOfTokens) + 1}`);
+    });
+
+    it('Should not reveal twice', async () => {
+      await expectError(send(provider, [await revealIx(revealedUri)], [payer]), 'Deployment has already been revealed');
+      await expectError(send(provider, [await drawRevealOffsetIx()], [payer]), 'Deployment has already been revealed');
+    });
+  });
+
+  describe('Random numbering', () => {
+    let deployment: TestDeployment;
+    let randomMinter: Keypair;
+    const maxNumberOfTokens = 4;
+    const mints: PublicKey[] = [];
+
+    const mintIxFor = async (edition: EditionAccounts) =>
+      mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: randomMinter.publicKey,
+        editions: [edition],
+      });
+
+    const drawIx = (hashlistIndices: number[], mintKeys: PublicKey[]) =>
+      editionsProgram.methods
+        .drawNumbers({ hashlistIndices })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          hashlist: deployment.hashlistPda,
+          hashlistPage: getHashlistPagePda(deployment.editionsPda, 0, editionsProgram.programId),
+          tokenIndices: deployment.tokenIndicesPda,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          payer: payer.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(mintKeys.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .instruction();
+
+    before(async () => {
+      randomMinter = Keypair.generate();
+      await airdrop(provider, randomMinter.publicKey);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        maxNumberOfTokens: new anchor.BN(maxNumberOfTokens),
+        randomNumbering: true,
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+
+      for (let i = 0; i < maxNumberOfTokens - 1; i++) {
+        const edition = newEdition(deployment, editionsProgram.programId, randomMinter.publicKey);
+        await send(provider, [await mintIxFor(edition)], [randomMinter, ...editionSigners([edition])]);
+        mints.push(edition.mint.publicKey);
+      }
+    });
+
+    it('Should mint without a number until it is drawn', async () => {
+      const metadata = await getTokenMetadata(provider.connection, mints[0]);
+      expect(metadata.name).to.equal('Item #?');
+      expect(metadata.uri).to.equal('ipfs://items/?');
+    });
+
+    it('Should not draw numbers out of hashlist order', async () => {
+      await expectError(send(provider, [await drawIx([1], [mints[1]])], [payer]), 'DrawOutOfOrder');
+    });
+
+    it('Should not draw a number in the transaction of the mint', async () => {
+      const edition = newEdition(deployment, editionsProgram.programId, randomMinter.publicKey);
+      await expectError(
+        send(
+          provider,
+          [
+            await mintIxFor(edition),
+            await drawIx([...mints.keys(), mints.length], [...mints, edition.mint.publicKey]),
+          ],
+          [randomMinter, payer, ...editionSigners([edition])]
+        ),
+        'Token numbers are drawn from a slot after the mint'
+      );
+
+      await send(provider, [await mintIxFor(edition)], [randomMinter, ...editionSigners([edition])]);
+      mints.push(edition.mint.publicKey);
+    });
+
+    it('Should draw every number once', async () => {
+      await send(provider, [await drawIx(mints.map((_, index) => index), mints)], [payer]);
+
+      const entries = await getHashlistEntries(provider.connection, deployment.editionsPda, editionsProgram);
+      const numbers = entries.map((entry) => entry.order.toNumber());
+      expect([...numbers].sort()).to.deep.equal([1, 2, 3, 4]);
+
+      for (const [index, mint] of mints.entries()) {
+        expect(entries[index].mint.toBase58()).to.equal(mint.toBase58());
+        const metadata = await getTokenMetadata(provider.connection, mint);
+        expect(metadata.name).to.equal(`Item #${numbers[index]}`);
+        expect(metadata.uri).to.equal(`ipfs://items/${numbers[index]}`);
+      }
+    });
+
+    it('Should leave drawn numbers alone', async () => {
+      const before = await getHashlistEntries(provider.connection, deployment.editionsPda, editionsProgram);
+      await send(provider, [await drawIx([0], [mints[0]])], [payer]);
+      const after = await getHashlistEntries(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(after[0].order.toNumber()).to.equal(before[0].order.toNumber());
+
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(editionsDecoded.data.nextDrawIndex).to.equal(maxNumberOfTokens);
+    });
+  });
+
+  describe('Hashlist pages', () => {
+    let deployment: TestDeployment;
+    const pageMinter = Keypair.generate().publicKey;
+
+    // anybody can send lamports to a page address before the page is created
+    const prefundIx = (pageIndex: number) =>
+      SystemProgram.transfer({
+        fromPubkey: payer.publicKey,
+        toPubkey: getHashlistPagePda(deployment.editionsPda, pageIndex, editionsProgram.programId),
+        lamports: 0.001 * LAMPORTS_PER_SOL,
+      });
+
+    const mintOne = async (pageIndex: number) => {
+      const edition = newEdition(deployment, editionsProgram.programId, pageMinter, { pageIndex });
+      await send(provider, [await mintEditionIx(provider, editionsProgram, deployment, pageMinter, edition)], [payer, ...editionSigners([edition])]);
+    };
+
+    before(async () => {
+      deployment = await deployEditions(provider, editionsProgram, {
+        maxNumberOfTokens: new anchor.BN(HASHLIST_PAGE_SIZE + 1),
+      });
+      await send(provider, [prefundIx(0), prefundIx(1)], [payer]);
+    });
+
+    it('Should fill a pre-funded page and roll over to the next pre-funded page', async () => {
+      // mints of the same page can land in any order, so they are sent in parallel batches
+      const batchSize = 25;
+      for (let minted = 0; minted < HASHLIST_PAGE_SIZE; minted += batchSize) {
+        await Promise.all(
+          Array.from({ length: Math.min(batchSize, HASHLIST_PAGE_SIZE - minted) }, () => mintOne(0))
+        );
+      }
+      await mintOne(1);
+
+      for (const pageIndex of [0, 1]) {
+        const page = await provider.connection.getAccountInfo(
+          getHashlistPagePda(deployment.editionsPda, pageIndex, editionsProgram.programId)
+        );
+        expect(page.owner.toBase58()).to.equal(editionsProgram.programId.toBase58());
+      }
+
+      const entries = await getHashlistEntries(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(entries.length).to.equal(HASHLIST_PAGE_SIZE + 1);
+      expect(entries.map((entry) => entry.order.toNumber())).to.deep.equal(
+        Array.from({ length: HASHLIST_PAGE_SIZE + 1 }, (_, index) => index + 1)
+      );
+    });
+  });
+
+  describe('Enforced royalties', () => {
+    let deployment: TestDeployment;
+    let seller: Keypair;
+    let buyer: Keypair;
+    let soldMint: PublicKey;
+
+    const mintEdition = async (target: TestDeployment, owner: Keypair) => {
+      const edition = newEdition(target, editionsProgram.programId, owner.publicKey, { transferHook: true });
+      await send(provider, [await mintEditionIx(provider, editionsProgram, target, owner.publicKey, edition)], [payer, ...editionSigners([edition])]);
+      return edition.mint.publicKey;
+    };
+
+    const payRoyaltiesIx = (mint: PublicKey, price: number) =>
+      editionsProgram.methods
+        .payRoyalties({ price: new anchor.BN(price) })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId),
+          royaltyConfig: deployment.royaltyConfigPda,
+          payer: buyer.publicKey,
+          mint,
+          sellerTokenAccount: getAssociatedTokenAddressSync(mint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+          approveAccount: getApproveAccountPda(mint, editionsProgram.programId),
+          paymentMint: null,
+          payerPaymentAccount: null,
+          paymentTokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([{ pubkey: payer.publicKey, isWritable: true, isSigner: false }])
+        .instruction();
+
+    before(async () => {
+      seller = Keypair.generate();
+      buyer = Keypair.generate();
+      await airdrop(provider, seller.publicKey);
+      await airdrop(provider, buyer.publicKey, 2);
+
+      // controls deployments always come with royalties, 5% to the creator by default
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        enforceRoyalties: true,
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+
+      const edition = newEdition(deployment, editionsProgram.programId, seller.publicKey, { transferHook: true });
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: seller.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [seller, ...editionSigners([edition])]);
+      soldMint = edition.mint.publicKey;
+    });
+
+    it('Should not transfer an edition without paying royalties', async () => {
+      await expectError(
+        send(provider, await transferEditionIxs(provider, soldMint, seller.publicKey, buyer.publicKey), [seller]),
+        'Missing approve account.'
+      );
+    });
+
+    it('Should transfer an edition once royalties are paid, and only once', async () => {
+      const creatorBalance = await provider.connection.getBalance(payer.publicKey);
+      await send(
+        provider,
+        [await payRoyaltiesIx(soldMint, LAMPORTS_PER_SOL), ...(await transferEditionIxs(provider, soldMint, seller.publicKey, buyer.publicKey))],
+        [buyer, seller]
+      );
+
+      const buyerTokenAccount = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(soldMint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        'confirmed',
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(buyerTokenAccount.amount)).to.equal(1);
+      // 5% of the price, minus the fee of the transaction the creator wallet paid for
+      expect(await provider.connection.getBalance(payer.publicKey)).to.be.greaterThan(creatorBalance + 0.04 * LAMPORTS_PER_SOL);
+
+      await expectError(
+        send(provider, await transferEditionIxs(provider, soldMint, buyer.publicKey, seller.publicKey), [buyer]),
+        'Approve account has expired.'
+      );
+    });
+
+    it('Should transfer freely while the deployment has no royalties', async () => {
+      const deploymentWithoutRoyalties = await deployEditions(provider, editionsProgram, { enforceRoyalties: true });
+      const freeMint = await mintEdition(deploymentWithoutRoyalties, seller);
+
+      await send(provider, await transferEditionIxs(provider, freeMint, seller.publicKey, buyer.publicKey), [seller]);
+
+      const buyerTokenAccount = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(freeMint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        'confirmed',
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(buyerTokenAccount.amount)).to.equal(1);
+    });
+  });
+
+  describe('Royalty payments', () => {
+    let deployment: TestDeployment;
+    let seller: Keypair;
+    let buyer: Keypair;
+    let paymentMint: PublicKey;
+    let buyerPaymentAccount: PublicKey;
+    const creators = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+    const price = LAMPORTS_PER_SOL;
+
+    const sellerTokenAccount = (mint: PublicKey) =>
+      getAssociatedTokenAddressSync(mint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    const mintToSeller = async () => {
+      const edition = newEdition(deployment, editionsProgram.programId, seller.publicKey, { transferHook: true });
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: seller.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [seller, ...editionSigners([edition])]);
+      return edition.mint.publicKey;
+    };
+
+    const payRoyaltiesIx = (
+      mint: PublicKey,
+      salePrice: number,
+      { tokenAccount = sellerTokenAccount(mint), splPayment = false } = {}
+    ) =>
+      editionsProgram.methods
+        .payRoyalties({ price: new anchor.BN(salePrice) })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId),
+          royaltyConfig: deployment.royaltyConfigPda,
+          payer: buyer.publicKey,
+          mint,
+          sellerTokenAccount: tokenAccount,
+          approveAccount: getApproveAccountPda(mint, editionsProgram.programId),
+          paymentMint: splPayment ? paymentMint : null,
+          payerPaymentAccount: splPayment ? buyerPaymentAccount : null,
+          paymentTokenProgram: splPayment ? TOKEN_PROGRAM_ID : null,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(
+          creators.map((creator) => ({
+            pubkey: splPayment ? getAssociatedTokenAddressSync(paymentMint, creator) : creator,
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+        .instruction();
+
+    const tokenBalance = async (tokenAccount: PublicKey, programId = TOKEN_2022_PROGRAM_ID) =>
+      Number((await getAccount(provider.connection, tokenAccount, 'confirmed', programId)).amount);
+
+    before(async () => {
+      seller = Keypair.generate();
+      buyer = Keypair.generate();
+      await airdrop(provider, seller.publicKey);
+      await airdrop(provider, buyer.publicKey, 2);
+
+      // 10% royalties, split 70 / 30
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        enforceRoyalties: true,
+        royalties: {
+          royaltyBasisPoints: new anchor.BN(1000),
+          creators: [
+            { address: creators[0], share: 70 },
+            { address: creators[1], share: 30 },
+          ],
+        },
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+
+      paymentMint = await createMint(provider.connection, payer, payer.publicKey, null, 6);
+      buyerPaymentAccount = await createAssociatedTokenAccount(provider.connection, payer, paymentMint, buyer.publicKey);
+      await mintTo(provider.connection, payer, paymentMint, buyerPaymentAccount, payer, 1_000_000_000);
+      for (const creator of creators) {
+        await createAssociatedTokenAccount(provider.connection, payer, paymentMint, creator);
+      }
+    });
+
+    it('Should not pay royalties on a zero price or a zero royalty', async () => {
+      const mint = await mintToSeller();
+      await expectError(send(provider, [await payRoyaltiesIx(mint, 0)], [buyer]), 'Sale price must be greater than zero.');
+      // 10% of 9 lamports rounds down to nothing
+      await expectError(send(provider, [await payRoyaltiesIx(mint, 9)], [buyer]), 'Royalty on this sale price is zero.');
+    });
+
+    it('Should split SOL royalties between the creators', async () => {
+      const mint = await mintToSeller();
+      await send(provider, [await payRoyaltiesIx(mint, price)], [buyer]);
+
+      expect(await provider.connection.getBalance(creators[0])).to.equal(0.07 * LAMPORTS_PER_SOL);
+      expect(await provider.connection.getBalance(creators[1])).to.equal(0.03 * LAMPORTS_PER_SOL);
+
+      const approveAccount = await editionsProgram.account.approveAccount.fetch(getApproveAccountPda(mint, editionsProgram.programId));
+      expect(approveAccount.tokenAccount.toBase58()).to.equal(sellerTokenAccount(mint).toBase58());
+      expect(approveAccount.owner.toBase58()).to.equal(seller.publicKey.toBase58());
+    });
+
+    it('Should split SPL royalties between the creators', async () => {
+      const mint = await mintToSeller();
+      await send(provider, [await payRoyaltiesIx(mint, 1_000_000, { splPayment: true })], [buyer]);
+
+      expect(await tokenBalance(getAssociatedTokenAddressSync(paymentMint, creators[0]), TOKEN_PROGRAM_ID)).to.equal(70_000);
+      expect(await tokenBalance(getAssociatedTokenAddressSync(paymentMint, creators[1]), TOKEN_PROGRAM_ID)).to.equal(30_000);
+    });
+
+    it('Should only approve a transfer out of the seller token account', async () => {
+      const mint = await mintToSeller();
+      // royalties paid on a sale out of somebody else's token account
+      const buyerTokenAccount = getAssociatedTokenAddressSync(mint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID);
+      await send(
+        provider,
+        [
+          createAssociatedTokenAccountIdempotentInstruction(payer.publicKey, buyerTokenAccount, buyer.publicKey, mint, TOKEN_2022_PROGRAM_ID),
+          await payRoyaltiesIx(mint, price, { tokenAccount: buyerTokenAccount }),
+        ],
+        [payer, buyer]
+      );
+
+      await expectError(
+        send(provider, await transferEditionIxs(provider, mint, seller.publicKey, buyer.publicKey), [seller]),
+        'Missing approve account.'
+      );
+
+      await send(provider, [await payRoyaltiesIx(mint, price)], [buyer]);
+      await send(provider, await transferEditionIxs(provider, mint, seller.publicKey, buyer.publicKey), [seller]);
+      expect(await tokenBalance(buyerTokenAccount)).to.equal(1);
+    });
+
+    it('Should not transfer once the approval has expired', async () => {
+      const mint = await mintToSeller();
+      await send(provider, [await payRoyaltiesIx(mint, price)], [buyer]);
+
+      const approveAccount = await editionsProgram.account.approveAccount.fetch(getApproveAccountPda(mint, editionsProgram.programId));
+      while ((await provider.connection.getSlot('confirmed')) <= approveAccount.expirySlot.toNumber()) {
+        await sleep(2000);
+      }
+
+      await expectError(
+        send(provider, await transferEditionIxs(provider, mint, seller.publicKey, buyer.publicKey), [seller]),
+        'Approve account has expired.'
+      );
+    });
+  });
+
+  describe('Soulbound and revocable editions', () => {
+    let holder: Keypair;
+    let thirdParty: Keypair;
+
+    const mintToHolder = async (deployment: TestDeployment) => {
+      const edition = newEdition(deployment, editionsProgram.programId, holder.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: holder.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [holder, ...editionSigners([edition])]);
+      return edition.mint.publicKey;
+    };
+
+    before(async () => {
+      holder = Keypair.generate();
+      thirdParty = Keypair.generate();
+      await airdrop(provider, holder.publicKey);
+    });
+
+    it('Should not transfer a soulbound edition', async () => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, { soulbound: true });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+      const mint = await mintToHolder(deployment);
+
+      await expectError(
+        send(provider, await transferEditionIxs(provider, mint, holder.publicKey, thirdParty.publicKey), [holder]),
+        'Transfer is disabled for this mint'
+      );
+    });
+
+    it('Should revoke an edition from a holder it was passed on to', async () => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, { revocable: true });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+      const mint = await mintToHolder(deployment);
+      await send(provider, await transferEditionIxs(provider, mint, holder.publicKey, thirdParty.publicKey), [holder]);
+
+      const thirdPartyTokenAccount = getAssociatedTokenAddressSync(mint, thirdParty.publicKey, false, TOKEN_2022_PROGRAM_ID);
+      const revokeIx = await editionsControlsProgram.methods
+        .revoke({ hashlistIndex: 0 })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          hashlist: deployment.hashlistPda,
+          hashlistPage: getHashlistPagePda(deployment.editionsPda, 0, editionsProgram.programId),
+          hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId),
+          creator: payer.publicKey,
+          holder: thirdParty.publicKey,
+          mint,
+          tokenAccount: thirdPartyTokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .instruction();
+      await send(provider, [revokeIx], [payer]);
+
+      const tokenAccount = await getAccount(provider.connection, thirdPartyTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      expect(Number(tokenAccount.amount)).to.equal(0);
+
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(editionsDecoded.data.numberOfTokensBurned.toNumber()).to.equal(1);
+      const entries = await getHashlistEntries(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(entries[0].order.testn(63)).to.be.true;
+    });
+  });
+
+  describe('Edition mint extensions', () => {
+    let holder: Keypair;
+    let thirdParty: Keypair;
+    const MEMO_PROGRAM_ID = new PublicKey('MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr');
+
+    const mintToHolder = async (deployment: TestDeployment, transferHook = false) => {
+      const edition = newEdition(deployment, editionsProgram.programId, holder.publicKey, { transferHook });
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: holder.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [holder, ...editionSigners([edition])]);
+      return edition.mint.publicKey;
+    };
+
+    const deploy = async (overrides: Parameters<typeof deployWithControls>[3]) => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, overrides);
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+      return deployment;
+    };
+
+    const tokenAccountState = async (mint: PublicKey, owner: PublicKey) =>
+      getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(mint, owner, false, TOKEN_2022_PROGRAM_ID),
+        'confirmed',
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    before(async () => {
+      holder = Keypair.generate();
+      thirdParty = Keypair.generate();
+      await airdrop(provider, holder.publicKey);
+    });
+
+    it('Should close the mint of a burned edition', async () => {
+      const deployment = await deploy({ mintCloseAuthority: true });
+      const mint = await mintToHolder(deployment);
+
+      const burnIx = await editionsProgram.methods
+        .burn({ hashlistIndex: 0 })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          hashlist: deployment.hashlistPda,
+          hashlistPage: getHashlistPagePda(deployment.editionsPda, 0, editionsProgram.programId),
+          hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId),
+          owner: holder.publicKey,
+          mint,
+          tokenAccount: getAssociatedTokenAddressSync(mint, holder.publicKey, false, TOKEN_2022_PROGRAM_ID),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+      await send(provider, [burnIx], [holder]);
+
+      expect(await provider.connection.getAccountInfo(mint)).to.be.null;
+    });
+
+    it('Should create token accounts frozen, except the one of the minter', async () => {
+      const deployment = await deploy({ defaultAccountFrozen: true });
+      const mint = await mintToHolder(deployment);
+      expect((await tokenAccountState(mint, holder.publicKey)).isFrozen).to.be.false;
+
+      // the new holder's token account starts out frozen, so the edition cannot be moved into it
+      await expectError(
+        send(provider, await transferEditionIxs(provider, mint, holder.publicKey, thirdParty.publicKey), [holder]),
+        'custom program error: 0x11'
+      );
+      const thirdPartyTokenAccount = getAssociatedTokenAddressSync(mint, thirdParty.publicKey, false, TOKEN_2022_PROGRAM_ID);
+      await send(provider, [(await transferEditionIxs(provider, mint, holder.publicKey, thirdParty.publicKey))[0]], [payer]);
+      expect((await tokenAccountState(mint, thirdParty.publicKey)).isFrozen).to.be.true;
+
+      const thawIx = await editionsControlsProgram.methods
+        .thawEditions()
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          creator: payer.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: thirdPartyTokenAccount, isWritable: true, isSigner: false },
+          { pubkey: mint, isWritable: false, isSigner: false },
+        ])
+        .instruction();
+      await send(provider, [thawIx], [payer]);
+      expect((await tokenAccountState(mint, thirdParty.publicKey)).isFrozen).to.be.false;
+
+      await send(provider, await transferEditionIxs(provider, mint, holder.publicKey, thirdParty.publicKey), [holder]);
+      expect(Number((await tokenAccountState(mint, thirdParty.publicKey)).amount)).to.equal(1);
+    });
+
+    it('Should only transfer an edition right after a memo when memos are required', async () => {
+      const deployment = await deploy({ requireTransferMemo: true });
+      const mint = await mintToHolder(deployment, true);
+
+      await expectError(
+        send(provider, await transferEditionIxs(provider, mint, holder.publicKey, thirdParty.publicKey), [holder]),
+        'Transfers of this edition must come right after a memo.'
+      );
+
+      const [createTokenAccountIx, transferIx] = await transferEditionIxs(provider, mint, holder.publicKey, thirdParty.publicKey);
+      const memoIx = new TransactionInstruction({ programId: MEMO_PROGRAM_ID, keys: [], data: Buffer.from('gift') });
+      await send(provider, [createTokenAccountIx, memoIx, transferIx], [holder]);
+      expect(Number((await tokenAccountState(mint, thirdParty.publicKey)).amount)).to.equal(1);
+    });
+  });
+
+  describe('Freezing until mint-out', () => {
+    let holder: Keypair;
+
+    const mintToHolder = async (deployment: TestDeployment) => {
+      const edition = newEdition(deployment, editionsProgram.programId, holder.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: holder.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [holder, ...editionSigners([edition])]);
+      return edition.mint.publicKey;
+    };
+
+    const deploy = async (overrides: Parameters<typeof deployWithControls>[3]) => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        freezeUntilMintOut: true,
+        ...overrides,
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+      return deployment;
+    };
+
+    const holderTokenAccount = (mint: PublicKey) =>
+      getAssociatedTokenAddressSync(mint, holder.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    const isFrozen = async (mint: PublicKey) =>
+      (await getAccount(provider.connection, holderTokenAccount(mint), 'confirmed', TOKEN_2022_PROGRAM_ID)).isFrozen;
+
+    const thawIx = (deployment: TestDeployment, mint: PublicKey) =>
+      editionsProgram.methods
+        .thaw()
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: holderTokenAccount(mint), isWritable: true, isSigner: false },
+          { pubkey: mint, isWritable: false, isSigner: false },
+        ])
+        .instruction();
+
+    before(async () => {
+      holder = Keypair.generate();
+      await airdrop(provider, holder.publicKey);
+    });
+
+    it('Should not freeze editions of an open edition without an unlock time', async () => {
+      await expectError(
+        deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+          freezeUntilMintOut: true,
+          maxNumberOfTokens: new anchor.BN(0),
+        }),
+        'Freezing until mint-out requires a max number of tokens, an unlock time or a mint end time'
+      );
+    });
+
+    it('Should keep editions frozen until the deployment mints out', async () => {
+      const deployment = await deploy({ maxNumberOfTokens: new anchor.BN(2) });
+      const firstMint = await mintToHolder(deployment);
+      expect(await isFrozen(firstMint)).to.be.true;
+
+      await expectError(send(provider, [await thawIx(deployment, firstMint)], [payer]), 'ThawLocked');
+
+      // the last mint unlocks the deployment, so its own edition is never frozen
+      const lastMint = await mintToHolder(deployment);
+      expect(await isFrozen(lastMint)).to.be.false;
+
+      await send(provider, [await thawIx(deployment, firstMint)], [payer]);
+      expect(await isFrozen(firstMint)).to.be.false;
+    });
+
+    it('Should unlock frozen editions when the deployment is closed before minting out', async () => {
+      const deployment = await deploy({ maxNumberOfTokens: new anchor.BN(2) });
+      const mint = await mintToHolder(deployment);
+      expect(await isFrozen(mint)).to.be.true;
+
+      const closeIx = await editionsControlsProgram.methods
+        .setDeploymentStatus({ status: { closed: {} } })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          creator: payer.publicKey,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .instruction();
+      await send(provider, [closeIx], [payer]);
+
+      await send(provider, [await thawIx(deployment, mint)], [payer]);
+      expect(await isFrozen(mint)).to.be.false;
+    });
+
+    it('Should burn a frozen edition', async () => {
+      const deployment = await deploy({ maxNumberOfTokens: new anchor.BN(2) });
+      const mint = await mintToHolder(deployment);
+      expect(await isFrozen(mint)).to.be.true;
+
+      const burnIx = await editionsProgram.methods
+        .burn({ hashlistIndex: 0 })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          hashlist: deployment.hashlistPda,
+          hashlistPage: getHashlistPagePda(deployment.editionsPda, 0, editionsProgram.programId),
+          hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId),
+          owner: holder.publicKey,
+          mint,
+          tokenAccount: holderTokenAccount(mint),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+      await send(provider, [burnIx], [holder]);
+
+      const tokenAccount = await getAccount(provider.connection, holderTokenAccount(mint), 'confirmed', TOKEN_2022_PROGRAM_ID);
+      expect(Number(tokenAccount.amount)).to.equal(0);
+    });
+
+    it('Should revoke a frozen edition', async () => {
+      const deployment = await deploy({ maxNumberOfTokens: new anchor.BN(2), revocable: true });
+      const mint = await mintToHolder(deployment);
+      expect(await isFrozen(mint)).to.be.true;
+
+      const revokeIx = await editionsControlsProgram.methods
+        .revoke({ hashlistIndex: 0 })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          hashlist: deployment.hashlistPda,
+          hashlistPage: getHashlistPagePda(deployment.editionsPda, 0, editionsProgram.programId),
+          hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId),
+          creator: payer.publicKey,
+          holder: holder.publicKey,
+          mint,
+          tokenAccount: holderTokenAccount(mint),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .instruction();
+      await send(provider, [revokeIx], [payer]);
+
+      const tokenAccount = await getAccount(provider.connection, holderTokenAccount(mint), 'confirmed', TOKEN_2022_PROGRAM_ID);
+      expect(Number(tokenAccount.amount)).to.equal(0);
+    });
+  });
+
+  describe('Mint window', () => {
+    let holder: Keypair;
+
+    const mintToHolder = async (deployment: TestDeployment) => {
+      const edition = newEdition(deployment, editionsProgram.programId, holder.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: holder.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [holder, ...editionSigners([edition])]);
+    };
+
+    const deploy = async (overrides: Parameters<typeof deployWithControls>[3]) => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, overrides);
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+      return deployment;
+    };
+
+    const finalizeIx = (deployment: TestDeployment) =>
+      editionsProgram.methods
+        .finalize()
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          group: deployment.group,
+          groupExtensionProgram: TOKEN_GROUP_EXTENSION_PROGRAM_ID,
+        })
+        .instruction();
+
+    const now = () => Math.floor(Date.now() / 1000);
+
+    before(async () => {
+      holder = Keypair.generate();
+      await airdrop(provider, holder.publicKey);
+    });
+
+    it('Should not mint before the mint start time', async () => {
+      const deployment = await deploy({ mintStartTime: new anchor.BN(now() + 3600) });
+      await expectError(mintToHolder(deployment), 'MintNotStarted');
+    });
+
+    it('Should not combine a mint end time with random numbering or a delayed reveal', async () => {
+      const mintEndTime = new anchor.BN(now() + 3600);
+      await expectError(
+        deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+          mintEndTime,
+          maxNumberOfTokens: new anchor.BN(4),
+          randomNumbering: true,
+        }),
+        'InvalidMintWindow'
+      );
+      await expectError(
+        deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+          mintEndTime,
+          itemBaseUri: 'ipfs://hidden/placeholder.json',
+          provenanceHash: [...createHash('sha256').update('ipfs://revealed/{}').digest()],
+        }),
+        'InvalidMintWindow'
+      );
+    });
+
+    it('Should not finalize while the mint window is open', async () => {
+      const deployment = await deploy({ mintEndTime: new anchor.BN(now() + 3600) });
+      await mintToHolder(deployment);
+      await expectError(send(provider, [await finalizeIx(deployment)], [payer]), 'MintWindowOpen');
+    });
+
+    it('Should not mint after the mint window has closed, and finalize at the number of editions issued', async () => {
+      const mintEndTime = now() + 5;
+      const deployment = await deploy({ mintEndTime: new anchor.BN(mintEndTime) });
+      await mintToHolder(deployment);
+      await mintToHolder(deployment);
+
+      await waitForClusterTime(provider, mintEndTime);
+      await expectError(mintToHolder(deployment), 'MintWindowClosed');
+
+      await send(provider, [await finalizeIx(deployment)], [payer]);
+      const editionsDecoded = await getEditions(provider.connection, deployment.editionsPda, editionsProgram);
+      expect(editionsDecoded.data.maxNumberOfTokens.toNumber()).to.equal(2);
+      expect(editionsDecoded.data.status).to.deep.equal({ mintedOut: {} });
+
+      await expectError(send(provider, [await finalizeIx(deployment)], [payer]), 'Invalid deployment status transition');
+    });
+
+    it('Should not finalize a deployment that has not minted any editions', async () => {
+      const mintEndTime = now() + 3;
+      const deployment = await deploy({ mintEndTime: new anchor.BN(mintEndTime) });
+
+      await waitForClusterTime(provider, mintEndTime);
+      await expectError(
+        send(provider, [await finalizeIx(deployment)], [payer]),
+        'Cannot finalize a deployment that has not minted any editions'
+      );
+    });
+  });
+
+  describe('Fixed max supply', () => {
+    const updateMaxSupplyIx = (deployment: TestDeployment, maxNumberOfTokens: number) =>
+      editionsControlsProgram.methods
+        .updateMaxSupply({ maxNumberOfTokens: new anchor.BN(maxNumberOfTokens) })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          creator: payer.publicKey,
+          group: deployment.group,
+          groupExtensionProgram: TOKEN_GROUP_EXTENSION_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .instruction();
+
+    it('Should not change the supply of a random numbering deployment', async () => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        maxNumberOfTokens: new anchor.BN(4),
+        randomNumbering: true,
+      });
+      await expectError(send(provider, [await updateMaxSupplyIx(deployment, 8)], [payer]), 'MaxSupplyFixed');
+    });
+
+    it('Should not change the supply of a delayed reveal deployment', async () => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        maxNumberOfTokens: new anchor.BN(10),
+        itemBaseUri: 'ipfs://hidden/placeholder.json',
+        provenanceHash: [...createHash('sha256').update('ipfs://revealed/{}').digest()],
+      });
+      await expectError(send(provider, [await updateMaxSupplyIx(deployment, 20)], [payer]), 'MaxSupplyFixed');
+    });
+
+    it('Should leave the supply to finalize once the mint window has closed', async () => {
+      const mintEndTime = Math.floor(Date.now() / 1000) + 3;
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        mintEndTime: new anchor.BN(mintEndTime),
+      });
+      await send(provider, [await updateMaxSupplyIx(deployment, 50)], [payer]);
+
+      await waitForClusterTime(provider, mintEndTime);
+      await expectError(send(provider, [await updateMaxSupplyIx(deployment, 60)], [payer]), 'MintWindowClosed');
+    });
+  });
+
+  describe('Platform fee recipients', () => {
+    let deployment: TestDeployment;
+    let feeMinter: Keypair;
+    const recipients = [Keypair.generate(), Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    // none of the shares divides the fee evenly
+    const shares = [31, 23, 29, 17];
+    const flatFee = 1_000_003;
+
+    const feeMintIx = (edition: EditionAccounts, feeRecipients: PublicKey[]) =>
+      mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: feeMinter.publicKey,
+        editions: [edition],
+        feeRecipients: feeRecipients.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })),
+      });
+
+    before(async () => {
+      feeMinter = Keypair.generate();
+      await airdrop(provider, feeMinter.publicKey);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        platformFee: {
+          platformFeeValue: new anchor.BN(flatFee),
+          recipients: recipients.map((recipient, i) => ({ address: recipient.publicKey, share: shares[i] })),
+          isFeeFlat: true,
+        },
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+
+      // rent exempt, so that each of them can receive its share of the fee
+      await send(
+        provider,
+        recipients.map((recipient) =>
+          SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: recipient.publicKey, lamports: 0.01 * LAMPORTS_PER_SOL })
+        ),
+        [payer]
+      );
+    });
+
+    it('Should split the fee between every recipient and give the rounding dust to the first one', async () => {
+      const balancesBefore = await Promise.all(recipients.map((recipient) => provider.connection.getBalance(recipient.publicKey)));
+
+      const edition = newEdition(deployment, editionsProgram.programId, feeMinter.publicKey);
+      await send(
+        provider,
+        [await feeMintIx(edition, recipients.map((recipient) => recipient.publicKey))],
+        [feeMinter, ...editionSigners([edition])]
+      );
+
+      const balancesAfter = await Promise.all(recipients.map((recipient) => provider.connection.getBalance(recipient.publicKey)));
+      const received = balancesAfter.map((balance, i) => balance - balancesBefore[i]);
+      expect(received).to.deep.equal([310_003, 230_000, 290_000, 170_000]);
+      expect(received.reduce((total, amount) => total + amount, 0)).to.equal(flatFee);
+    });
+
+    it('Should not pay a fee recipient account that does not match the stored address', async () => {
+      const edition = newEdition(deployment, editionsProgram.programId, feeMinter.publicKey);
+      const [first, second, ...rest] = recipients.map((recipient) => recipient.publicKey);
+      await expectError(
+        send(provider, [await feeMintIx(edition, [second, first, ...rest])], [feeMinter, ...editionSigners([edition])]),
+        'Recipient account does not match the expected address.'
+      );
+    });
+  });
+
+  describe('Updating item metadata in batches', () => {
+    let deployment: TestDeployment;
+    let batchMinter: Keypair;
+    const mints: PublicKey[] = [];
+
+    const itemAccounts = (pairs: [PublicKey, PublicKey][]) =>
+      pairs.flatMap(([mint, hashlistMarker]) => [
+        { pubkey: mint, isWritable: true, isSigner: false },
+        { pubkey: hashlistMarker, isWritable: false, isSigner: false },
+      ]);
+
+    const markerOf = (mint: PublicKey) => getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId);
+
+    const batchIx = (args: { name: string | null; uri: string | null; additionalMetadata: { field: string; value: string }[] }[], remainingAccounts: ReturnType<typeof itemAccounts>) =>
+      editionsControlsProgram.methods
+        .updateItemMetadataBatch(args)
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          payer: payer.publicKey,
+          creator: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction();
+
+    before(async () => {
+      batchMinter = Keypair.generate();
+      await airdrop(provider, batchMinter.publicKey);
+
+      deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram);
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+
+      for (let i = 0; i < 2; i++) {
+        const edition = newEdition(deployment, editionsProgram.programId, batchMinter.publicKey);
+        const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+          minter: batchMinter.publicKey,
+          editions: [edition],
+        });
+        await send(provider, [mintIx], [batchMinter, ...editionSigners([edition])]);
+        mints.push(edition.mint.publicKey);
+      }
+    });
+
+    it('Should update several editions in one call', async () => {
+      const updateIx = await batchIx(
+        [
+          { name: 'Batch #1', uri: null, additionalMetadata: [{ field: 'background', value: 'red' }] },
+          { name: null, uri: 'https://example.com/batch/2', additionalMetadata: [] },
+        ],
+        itemAccounts(mints.map((mint) => [mint, markerOf(mint)]))
+      );
+      await send(provider, [updateIx], [payer]);
+
+      const first = await getTokenMetadata(provider.connection, mints[0]);
+      expect(first.name).to.equal('Batch #1');
+      expect(first.additionalMetadata['background']).to.equal('red');
+      const second = await getTokenMetadata(provider.connection, mints[1]);
+      expect(second.uri).to.equal('https://example.com/batch/2');
+    });
+
+    it('Should reject an edition passed with the hashlist marker of another edition', async () => {
+      const updateIx = await batchIx(
+        [
+          { name: 'Batch #1', uri: null, additionalMetadata: [] },
+          { name: 'Batch #2', uri: null, additionalMetadata: [] },
+        ],
+        itemAccounts([
+          [mints[0], markerOf(mints[0])],
+          [mints[1], markerOf(mints[0])],
+        ])
+      );
+      await expectError(send(provider, [updateIx], [payer]), 'Hashlist entry does not match the mint');
+    });
+
+    it('Should reject a batch without a mint and a hashlist marker for every item', async () => {
+      const updateIx = await batchIx(
+        [
+          { name: 'Batch #1', uri: null, additionalMetadata: [] },
+          { name: 'Batch #2', uri: null, additionalMetadata: [] },
+        ],
+        [...itemAccounts([[mints[0], markerOf(mints[0])]]), { pubkey: mints[1], isWritable: true, isSigner: false }]
+      );
+      await expectError(
+        send(provider, [updateIx], [payer]),
+        'Mint and hashlist marker are required in the remaining accounts for every item'
+      );
+    });
+
+    it('Should not overwrite royalty or creator fields', async () => {
+      for (const field of ['royalty_basis_points', 'creator:0']) {
+        const updateIx = await batchIx(
+          [{ name: null, uri: null, additionalMetadata: [{ field, value: '0' }] }],
+          itemAccounts([[mints[0], markerOf(mints[0])]])
+        );
+        await expectError(send(provider, [updateIx], [payer]), 'Invalid field. You cannot use a public key as a field.');
+      }
+    });
+  });
+
+  describe('Deployment lifecycle', () => {
+    let statusMinter: Keypair;
+
+    type DeploymentStatus = Parameters<typeof editionsControlsProgram.methods.setDeploymentStatus>[0]['status'];
+
+    const setStatusIx = (deployment: TestDeployment, status: DeploymentStatus) =>
+      editionsControlsProgram.methods
+        .setDeploymentStatus({ status })
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          editionsControls: deployment.editionsControlsPda,
+          creator: payer.publicKey,
+          raribleEditionsProgram: editionsProgram.programId,
+        })
+        .instruction();
+
+    const mintOne = async (deployment: TestDeployment) => {
+      const edition = newEdition(deployment, editionsProgram.programId, statusMinter.publicKey);
+      const mintIx = await mintWithControlsIx(editionsControlsProgram, editionsProgram, deployment, {
+        minter: statusMinter.publicKey,
+        editions: [edition],
+      });
+      await send(provider, [mintIx], [statusMinter, ...editionSigners([edition])]);
+    };
+
+    const deploy = async () => {
+      const deployment = await deployWithControls(provider, editionsControlsProgram, editionsProgram, {
+        maxNumberOfTokens: new anchor.BN(2),
+      });
+      await addPhase(provider, editionsControlsProgram, editionsProgram, deployment, phaseConfig());
+      return deployment;
+    };
+
+    const statusOf = async (deployment: TestDeployment) =>
+      (await getEditions(provider.connection, deployment.editionsPda, editionsProgram)).data.status;
+
+    before(async () => {
+      statusMinter = Keypair.generate();
+      await airdrop(provider, statusMinter.publicKey);
+    });
+
+    it('Should only mint from a live deployment, and mint it out at its max number of tokens', async () => {
+      const deployment = await deploy();
+
+      await send(provider, [await setStatusIx(deployment, { draft: {} })], [payer]);
+      await expectError(mintOne(deployment), 'Deployment is not live');
+
+      await send(provider, [await setStatusIx(deployment, { live: {} })], [payer]);
+      await send(provider, [await setStatusIx(deployment, { paused: {} })], [payer]);
+      await expectError(mintOne(deployment), 'Deployment is not live');
+
+      await send(provider, [await setStatusIx(deployment, { live: {} })], [payer]);
+      await mintOne(deployment);
+      expect(await statusOf(deployment)).to.deep.equal({ live: {} });
+
+      await mintOne(deployment);
+      expect(await statusOf(deployment)).to.deep.equal({ mintedOut: {} });
+      await expectError(mintOne(deployment), 'Minted out');
+    });
+
+    it('Should not mint from a closed deployment, or reopen it', async () => {
+      const deployment = await deploy();
+      await mintOne(deployment);
+
+      await send(provider, [await setStatusIx(deployment, { closed: {} })], [payer]);
+      expect(await statusOf(deployment)).to.deep.equal({ closed: {} });
+      await expectError(mintOne(deployment), 'Deployment is not live');
+
+      const statuses: DeploymentStatus[] = [{ live: {} }, { paused: {} }, { draft: {} }, { mintedOut: {} }];
+      for (const status of statuses) {
+        await expectError(send(provider, [await setStatusIx(deployment, status)], [payer]), 'Invalid deployment status transition');
+      }
+      expect(await statusOf(deployment)).to.deep.equal({ closed: {} });
+    });
+  });
+
+  describe('Royalty config and creator fields', () => {
+    let deployment: TestDeployment;
+    const creatorA = Keypair.generate().publicKey;
+    const creatorB = Keypair.generate().publicKey;
+
+    const modifyRoyaltiesIx = (mint: PublicKey, creators: { address: PublicKey; share: number }[]) =>
+      editionsProgram.methods
+        .modifyRoyalties({ royaltyBasisPoints: 750, creators })
+        .accountsStrict({
+          payer: payer.publicKey,
+          editionsDeployment: deployment.editionsPda,
+          royaltyConfig: deployment.royaltyConfigPda,
+          signer: payer.publicKey,
+          mint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+
+    const syncRoyaltiesIx = (mint: PublicKey) =>
+      editionsProgram.methods
+        .syncRoyalties()
+        .accountsStrict({
+          editionsDeployment: deployment.editionsPda,
+          royaltyConfig: deployment.royaltyConfigPda,
+          hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint, editionsProgram.programId),
+          payer: payer.publicKey,
+          mint,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .instruction();
+
+    before(async () => {
+      // deployed without controls, so that it starts without a royalty config
+      deployment = await deployEditions(provider, editionsProgram);
+    });
+
+    it('Should create the royalty config on the first royalty update and write creator fields', async () => {
+      expect(await provider.connection.getAccountInfo(deployment.royaltyConfigPda)).to.be.null;
+
+      await send(provider, [await modifyRoyaltiesIx(deployment.groupMint, [{ address: creatorA, share: 60 }, { address: creatorB, share: 40 }])], [payer]);
+
+      const royaltyConfig = await editionsProgram.account.royaltyConfig.fetch(deployment.royaltyConfigPda);
+      expect(royaltyConfig.editionsDeployment.toBase58()).to.equal(deployment.editionsPda.toBase58());
+      expect(royaltyConfig.royaltyBasisPoints).to.equal(750);
+      expect(royaltyConfig.creators.map((creator) => [creator.address.toBase58(), creator.share, creator.verified])).to.deep.equal([
+        [creatorA.toBase58(), 60, false],
+        [creatorB.toBase58(), 40, false],
+      ]);
+
+      const metadata = await getTokenMetadata(provider.connection, deployment.groupMint);
+      expect(metadata.additionalMetadata['royalty_basis_points']).to.equal('750');
+      expect(metadata.additionalMetadata['creator:0']).to.equal(`${creatorA.toBase58()}:60:0`);
+      expect(metadata.additionalMetadata['creator:1']).to.equal(`${creatorB.toBase58()}:40:0`);
+    });
+
+    it('Should sync a shorter creator list to a minted edition and drop the surplus field', async () => {
+      const edition = newEdition(deployment, editionsProgram.programId, payer.publicKey);
+      await send(provider, [await mintEditionIx(provider, editionsProgram, deployment, payer.publicKey, edition)], [payer, ...editionSigners([edition])]);
+      const mint = edition.mint.publicKey;
+      expect((await getTokenMetadata(provider.connection, mint)).additionalMetadata['creator:1']).to.equal(`${creatorB.toBase58()}:40:0`);
+
+      await send(provider, [await modifyRoyaltiesIx(deployment.groupMint, [{ address: creatorB, share: 100 }]), await syncRoyaltiesIx(mint)], [payer]);
+
+      const metadata = await getTokenMetadata(provider.connection, mint);
+      expect(metadata.additionalMetadata['royalty_basis_points']).to.equal('750');
+      expect(metadata.additionalMetadata['creator:0']).to.equal(`${creatorB.toBase58()}:100:0`);
+      expect(metadata.additionalMetadata).to.not.have.property('creator:1');
+    });
+
+    it('Should replace legacy creator fields keyed by address', async () => {
+      // a mint written before creator:<index> fields, handed over to the deployment
+      const legacyMint = Keypair.generate();
+      const mintLen = getMintLen([ExtensionType.MetadataPointer]);
+      await send(
+        provider,
+        [
+          SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: legacyMint.publicKey,
+            space: mintLen,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(mintLen),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeMetadataPointerInstruction(legacyMint.publicKey, payer.publicKey, legacyMint.publicKey, TOKEN_2022_PROGRAM_ID),
+          createInitializeMintInstruction(legacyMint.publicKey, 0, payer.publicKey, null, TOKEN_2022_PROGRAM_ID),
+        ],
+        [payer, legacyMint]
+      );
+      await tokenMetadataInitializeWithRentTransfer(
+        provider.connection, payer, legacyMint.publicKey, payer.publicKey, payer, 'Legacy', 'LGCY', 'ipfs://legacy', [], undefined, TOKEN_2022_PROGRAM_ID
+      );
+      await tokenMetadataUpdateFieldWithRentTransfer(
+        provider.connection, payer, legacyMint.publicKey, payer, creatorA.toBase58(), '60', [], undefined, TOKEN_2022_PROGRAM_ID
+      );
+      await tokenMetadataUpdateFieldWithRentTransfer(
+        provider.connection, payer, legacyMint.publicKey, payer, 'background', 'blue', [], undefined, TOKEN_2022_PROGRAM_ID
+      );
+      await tokenMetadataUpdateAuthority(
+        provider.connection, payer, legacyMint.publicKey, payer, deployment.editionsPda, [], undefined, TOKEN_2022_PROGRAM_ID
+      );
+
+      // goes through the same metadata writer as sync_royalties
+      await send(provider, [await modifyRoyaltiesIx(legacyMint.publicKey, [{ address: creatorA, share: 100 }])], [payer]);
+
+      const metadata = await getTokenMetadata(provider.connection, legacyMint.publicKey);
+      expect(metadata.additionalMetadata).to.not.have.property(creatorA.toBase58());
+      expect(metadata.additionalMetadata['creator:0']).to.equal(`${creatorA.toBase58()}:100:0`);
+      expect(metadata.additionalMetadata['royalty_basis_points']).to.equal('750');
+      expect(metadata.additionalMetadata['background']).to.equal('blue');
+    });
+  });
 });
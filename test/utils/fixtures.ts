@@ -0,0 +1,467 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import {
+  AccountMeta,
+  ComputeBudgetProgram,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from '@solana/web3.js';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  getAssociatedTokenAddressSync,
+  TOKEN_2022_PROGRAM_ID,
+} from '@solana/spl-token';
+import { expect } from 'chai';
+import { RaribleEditionsControls } from '../../target/types/rarible_editions_controls';
+import { RaribleEditions } from '../../target/types/rarible_editions';
+import { TOKEN_GROUP_EXTENSION_PROGRAM_ID } from '../../constants';
+import {
+  getEditionsControlsPda,
+  getEditionsPda,
+  getExtraMetasPda,
+  getHashlistMarkerPda,
+  getHashlistPagePda,
+  getHashlistPda,
+  getMinterStatsPda,
+  getMinterStatsPhasePda,
+  getRoyaltyConfigPda,
+  getTokenIndicesPda,
+} from './pdas';
+import { PhaseConfig } from './types';
+
+// Shared setup for the suites that need a deployment of their own, so that they don't depend on
+// the state left behind by the main suite.
+
+export const NATIVE_MINT = new PublicKey('So11111111111111111111111111111111111111112');
+
+type ControlsInput = Parameters<Program<RaribleEditionsControls>['methods']['initialiseEditionsControls']>[0];
+type EditionsInput = Parameters<Program<RaribleEditions>['methods']['initialise']>[0];
+type MintInput = Parameters<Program<RaribleEditionsControls>['methods']['mintWithControls']>[0];
+
+export interface TestDeployment {
+  symbol: string;
+  editionsPda: PublicKey;
+  editionsControlsPda: PublicKey;
+  hashlistPda: PublicKey;
+  royaltyConfigPda: PublicKey;
+  tokenIndicesPda: PublicKey;
+  groupMint: PublicKey;
+  group: PublicKey;
+  treasury: PublicKey;
+  platformFeeRecipient: PublicKey;
+}
+
+// the accounts of a single edition, in the order mint_with_controls expects them in the remaining accounts
+export interface EditionAccounts {
+  mint: Keypair;
+  member: Keypair;
+  hashlistMarker: PublicKey;
+  tokenAccount: PublicKey;
+  hashlistPage: PublicKey;
+  extraMetasAccount: PublicKey | null;
+}
+
+export const computeUnits = () => ComputeBudgetProgram.setComputeUnitLimit({ units: 800000 });
+
+export const send = (provider: anchor.AnchorProvider, instructions: TransactionInstruction[], signers: Signer[] = []) =>
+  provider.sendAndConfirm(new Transaction().add(computeUnits(), ...instructions), signers);
+
+export const expectError = async (promise: Promise<unknown>, message: string) => {
+  try {
+    await promise;
+  } catch (error) {
+    expect(JSON.stringify(error)).to.include(message);
+    return;
+  }
+  expect.fail('Transaction should have failed');
+};
+
+export const airdrop = async (provider: anchor.AnchorProvider, address: PublicKey, sol = 1) => {
+  const signature = await provider.connection.requestAirdrop(address, sol * LAMPORTS_PER_SOL);
+  await provider.connection.confirmTransaction(signature);
+};
+
+export const now = () => Math.floor(Date.now() / 1000);
+
+export const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+// on-chain times are checked against the cluster clock, which can lag behind the local one
+export const waitForClusterTime = async (provider: anchor.AnchorProvider, timestamp: number) => {
+  while ((await provider.connection.getBlockTime(await provider.connection.getSlot('confirmed'))) <= timestamp) {
+    await sleep(500);
+  }
+};
+
+const newDeployment = (editionsProgramId: PublicKey, controlsProgramId: PublicKey, symbol: string): TestDeployment => {
+  const editionsPda = getEditionsPda(symbol, editionsProgramId);
+  return {
+    symbol,
+    editionsPda,
+    editionsControlsPda: getEditionsControlsPda(editionsPda, controlsProgramId),
+    hashlistPda: getHashlistPda(editionsPda, editionsProgramId),
+    royaltyConfigPda: getRoyaltyConfigPda(editionsPda, editionsProgramId),
+    tokenIndicesPda: getTokenIndicesPda(editionsPda, editionsProgramId),
+    groupMint: PublicKey.default,
+    group: PublicKey.default,
+    treasury: Keypair.generate().publicKey,
+    platformFeeRecipient: Keypair.generate().publicKey,
+  };
+};
+
+// funds the treasury and the fee recipient up to rent exemption so that they can receive small payments
+const fundIxs = (payer: PublicKey, deployment: TestDeployment) =>
+  [deployment.treasury, deployment.platformFeeRecipient].map((toPubkey) =>
+    SystemProgram.transfer({ fromPubkey: payer, toPubkey, lamports: 0.01 * LAMPORTS_PER_SOL })
+  );
+
+// Deploys a collection through editions controls. The provider wallet is the creator, there is no
+// platform fee and the royalties go to the creator, unless overridden.
+export const deployWithControls = async (
+  provider: anchor.AnchorProvider,
+  editionsControlsProgram: Program<RaribleEditionsControls>,
+  editionsProgram: Program<RaribleEditions>,
+  overrides: Partial<ControlsInput> = {}
+): Promise<TestDeployment> => {
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const groupMint = Keypair.generate();
+  const group = Keypair.generate();
+  const deployment = newDeployment(
+    editionsProgram.programId,
+    editionsControlsProgram.programId,
+    overrides.symbol ?? Keypair.generate().publicKey.toBase58().slice(0, 8)
+  );
+  deployment.groupMint = groupMint.publicKey;
+  deployment.group = group.publicKey;
+  deployment.treasury = overrides.treasury ?? deployment.treasury;
+
+  const input: ControlsInput = {
+    maxMintsPerWallet: new anchor.BN(100),
+    treasury: deployment.treasury,
+    maxNumberOfTokens: new anchor.BN(100),
+    symbol: deployment.symbol,
+    collectionName: 'Test collection',
+    collectionUri: 'ipfs://collection',
+    cosignerProgramId: null,
+    royalties: {
+      royaltyBasisPoints: new anchor.BN(500),
+      creators: [{ address: payer.publicKey, share: 100 }],
+    },
+    platformFee: {
+      platformFeeValue: new anchor.BN(0),
+      recipients: [{ address: deployment.platformFeeRecipient, share: 100 }],
+      isFeeFlat: true,
+    },
+    extraMeta: [],
+    itemBaseUri: 'ipfs://items/{}',
+    itemBaseName: 'Item #{}',
+    provenanceHash: null,
+    randomNumbering: false,
+    enforceRoyalties: false,
+    soulbound: false,
+    revocable: false,
+    mintCloseAuthority: false,
+    defaultAccountFrozen: false,
+    requireTransferMemo: false,
+    freezeUntilMintOut: false,
+    unlockTime: null,
+    mintStartTime: null,
+    mintEndTime: null,
+    ...overrides,
+  };
+
+  const initialiseIx = await editionsControlsProgram.methods
+    .initialiseEditionsControls(input)
+    .accountsStrict({
+      editionsControls: deployment.editionsControlsPda,
+      editionsDeployment: deployment.editionsPda,
+      hashlist: deployment.hashlistPda,
+      royaltyConfig: deployment.royaltyConfigPda,
+      payer: payer.publicKey,
+      creator: payer.publicKey,
+      groupMint: groupMint.publicKey,
+      group: group.publicKey,
+      tokenIndices: input.randomNumbering ? deployment.tokenIndicesPda : null,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      raribleEditionsProgram: editionsProgram.programId,
+      groupExtensionProgram: TOKEN_GROUP_EXTENSION_PROGRAM_ID,
+    })
+    .instruction();
+
+  await send(provider, [...fundIxs(payer.publicKey, deployment), initialiseIx], [groupMint, group, payer]);
+
+  return deployment;
+};
+
+// Deploys a collection straight on rarible_editions, without controls. The provider wallet is the
+// creator and, without a cosigner, anybody can mint.
+export const deployEditions = async (
+  provider: anchor.AnchorProvider,
+  editionsProgram: Program<RaribleEditions>,
+  overrides: Partial<EditionsInput> = {}
+): Promise<TestDeployment> => {
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const groupMint = Keypair.generate();
+  const group = Keypair.generate();
+  const deployment = newDeployment(
+    editionsProgram.programId,
+    PublicKey.default,
+    overrides.symbol ?? Keypair.generate().publicKey.toBase58().slice(0, 8)
+  );
+  deployment.groupMint = groupMint.publicKey;
+  deployment.group = group.publicKey;
+
+  const input: EditionsInput = {
+    symbol: deployment.symbol,
+    collectionName: 'Test collection',
+    collectionUri: 'ipfs://collection',
+    maxNumberOfTokens: new anchor.BN(100),
+    creatorCosignProgramId: null,
+    itemBaseUri: 'ipfs://items/{}',
+    itemBaseName: 'Item #{}',
+    provenanceHash: null,
+    randomNumbering: false,
+    enforceRoyalties: false,
+    soulbound: false,
+    revocable: false,
+    mintCloseAuthority: false,
+    defaultAccountFrozen: false,
+    requireTransferMemo: false,
+    freezeUntilMintOut: false,
+    unlockTime: null,
+    mintStartTime: null,
+    mintEndTime: null,
+    ...overrides,
+  };
+
+  const initialiseIx = await editionsProgram.methods
+    .initialise(input)
+    .accountsStrict({
+      editionsDeployment: deployment.editionsPda,
+      hashlist: deployment.hashlistPda,
+      payer: payer.publicKey,
+      creator: payer.publicKey,
+      groupMint: groupMint.publicKey,
+      group: group.publicKey,
+      tokenIndices: input.randomNumbering ? deployment.tokenIndicesPda : null,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      groupExtensionProgram: TOKEN_GROUP_EXTENSION_PROGRAM_ID,
+    })
+    .instruction();
+
+  await send(provider, [initialiseIx], [groupMint, group, payer]);
+
+  return deployment;
+};
+
+// An open, free, native SOL phase running for a day.
+export const phaseConfig = (overrides: Partial<PhaseConfig> = {}): PhaseConfig => ({
+  maxMintsPerWallet: new anchor.BN(100),
+  maxMintsTotal: new anchor.BN(1000),
+  priceAmount: new anchor.BN(0),
+  startTime: new anchor.BN(now() - 60),
+  endTime: new anchor.BN(now() + 60 * 60 * 24),
+  priceToken: NATIVE_MINT,
+  isPrivate: false,
+  merkleRoot: null,
+  dutchAuction: null,
+  tokenGate: null,
+  ...overrides,
+});
+
+export const addPhase = async (
+  provider: anchor.AnchorProvider,
+  editionsControlsProgram: Program<RaribleEditionsControls>,
+  editionsProgram: Program<RaribleEditions>,
+  deployment: TestDeployment,
+  phase: PhaseConfig
+) => {
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const phaseIx = await editionsControlsProgram.methods
+    .addPhase(phase)
+    .accountsStrict({
+      editionsControls: deployment.editionsControlsPda,
+      creator: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      raribleEditionsProgram: editionsProgram.programId,
+    })
+    .instruction();
+
+  await send(provider, [phaseIx], [payer]);
+};
+
+export const newEdition = (
+  deployment: TestDeployment,
+  editionsProgramId: PublicKey,
+  owner: PublicKey,
+  // transferHook: the deployment enforces royalties or requires transfer memos
+  { pageIndex = 0, transferHook = false } = {}
+): EditionAccounts => {
+  const mint = Keypair.generate();
+  return {
+    mint,
+    member: Keypair.generate(),
+    hashlistMarker: getHashlistMarkerPda(deployment.editionsPda, mint.publicKey, editionsProgramId),
+    tokenAccount: getAssociatedTokenAddressSync(mint.publicKey, owner, false, TOKEN_2022_PROGRAM_ID),
+    hashlistPage: getHashlistPagePda(deployment.editionsPda, pageIndex, editionsProgramId),
+    extraMetasAccount: transferHook ? getExtraMetasPda(mint.publicKey, editionsProgramId) : null,
+  };
+};
+
+export interface MintWithControlsOptions {
+  minter: PublicKey;
+  payer?: PublicKey;
+  phaseIndex?: number;
+  // the first edition goes in the named accounts, the others in the remaining accounts
+  editions: EditionAccounts[];
+  input?: Partial<MintInput>;
+  accounts?: Record<string, PublicKey | null>;
+  // defaults to the deployment fee recipient wallet
+  feeRecipients?: AccountMeta[];
+}
+
+export const mintWithControlsIx = (
+  editionsControlsProgram: Program<RaribleEditionsControls>,
+  editionsProgram: Program<RaribleEditions>,
+  deployment: TestDeployment,
+  { minter, payer = minter, phaseIndex = 0, editions, input = {}, accounts = {}, feeRecipients }: MintWithControlsOptions
+) => {
+  const [first, ...others] = editions;
+  const editionRemainingAccounts = others.flatMap((edition) => [
+    { pubkey: edition.mint.publicKey, isWritable: true, isSigner: true },
+    { pubkey: edition.member.publicKey, isWritable: true, isSigner: true },
+    { pubkey: edition.hashlistMarker, isWritable: true, isSigner: false },
+    { pubkey: edition.tokenAccount, isWritable: true, isSigner: false },
+    { pubkey: edition.hashlistPage, isWritable: true, isSigner: false },
+    ...(edition.extraMetasAccount ? [{ pubkey: edition.extraMetasAccount, isWritable: true, isSigner: false }] : []),
+  ]);
+
+  return editionsControlsProgram.methods
+    .mintWithControls({
+      phaseIndex,
+      merkleProof: null,
+      allowListPrice: null,
+      allowListMaxClaims: null,
+      quantity: editions.length,
+      gateHashlistIndex: null,
+      cosign: null,
+      ...input,
+    })
+    .accountsStrict({
+      editionsDeployment: deployment.editionsPda,
+      editionsControls: deployment.editionsControlsPda,
+      hashlist: deployment.hashlistPda,
+      hashlistMarker: first.hashlistMarker,
+      hashlistPage: first.hashlistPage,
+      payer,
+      signer: payer,
+      minter,
+      minterStats: getMinterStatsPda(deployment.editionsPda, minter, editionsControlsProgram.programId),
+      minterStatsPhase: getMinterStatsPhasePda(deployment.editionsPda, minter, phaseIndex, editionsControlsProgram.programId),
+      mint: first.mint.publicKey,
+      member: first.member.publicKey,
+      group: deployment.group,
+      groupMint: deployment.groupMint,
+      paymentMint: null,
+      payerPaymentAccount: null,
+      treasuryPaymentAccount: null,
+      paymentTokenProgram: null,
+      gateTokenAccount: null,
+      gateMember: null,
+      gateMarker: null,
+      gateMint: null,
+      gateTokenProgram: null,
+      gateDeployment: null,
+      gateHashlist: null,
+      gateHashlistMarker: null,
+      gateHashlistPage: null,
+      extraMetasAccount: first.extraMetasAccount,
+      instructionsSysvar: null,
+      tokenAccount: first.tokenAccount,
+      treasury: deployment.treasury,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      groupExtensionProgram: TOKEN_GROUP_EXTENSION_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      raribleEditionsProgram: editionsProgram.programId,
+      ...accounts,
+    })
+    .remainingAccounts([
+      ...(feeRecipients ?? [{ pubkey: deployment.platformFeeRecipient, isWritable: true, isSigner: false }]),
+      ...editionRemainingAccounts,
+    ])
+    .instruction();
+};
+
+// keypairs that have to sign a mint of the given editions
+export const editionSigners = (editions: EditionAccounts[]) => editions.flatMap((edition) => [edition.mint, edition.member]);
+
+// Mints a single edition straight on rarible_editions, signed by the provider wallet.
+export const mintEditionIx = (
+  provider: anchor.AnchorProvider,
+  editionsProgram: Program<RaribleEditions>,
+  deployment: TestDeployment,
+  minter: PublicKey,
+  edition: EditionAccounts,
+  accounts: Record<string, PublicKey | null> = {}
+) =>
+  editionsProgram.methods
+    .mint()
+    .accountsStrict({
+      editionsDeployment: deployment.editionsPda,
+      hashlist: deployment.hashlistPda,
+      hashlistPage: edition.hashlistPage,
+      hashlistMarker: edition.hashlistMarker,
+      payer: provider.wallet.publicKey,
+      signer: provider.wallet.publicKey,
+      minter,
+      mint: edition.mint.publicKey,
+      member: edition.member.publicKey,
+      group: deployment.group,
+      groupMint: deployment.groupMint,
+      tokenAccount: edition.tokenAccount,
+      extraMetasAccount: edition.extraMetasAccount,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      groupExtensionProgram: TOKEN_GROUP_EXTENSION_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      ...accounts,
+    })
+    .instruction();
+
+// Moves an edition from one wallet to another, creating the destination token account. The accounts
+// of the royalty transfer hook are resolved from the mint.
+export const transferEditionIxs = async (
+  provider: anchor.AnchorProvider,
+  mint: PublicKey,
+  from: PublicKey,
+  to: PublicKey
+) => {
+  const source = getAssociatedTokenAddressSync(mint, from, false, TOKEN_2022_PROGRAM_ID);
+  const destination = getAssociatedTokenAddressSync(mint, to, false, TOKEN_2022_PROGRAM_ID);
+  return [
+    createAssociatedTokenAccountIdempotentInstruction(provider.wallet.publicKey, destination, to, mint, TOKEN_2022_PROGRAM_ID),
+    await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      source,
+      mint,
+      destination,
+      from,
+      BigInt(1),
+      0,
+      [],
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
+    ),
+  ];
+};
@@ -4,6 +4,7 @@ import { RaribleEditionsControls } from '../../target/types/rarible_editions_con
 import { RaribleEditions } from '../../target/types/rarible_editions';
 import { IdlAccounts } from '@coral-xyz/anchor';
 import { getTokenMetadata as getSplTokenMetadata } from '@solana/spl-token';
+import { getHashlistPda, getHashlistPagePda, HASHLIST_PAGE_SIZE } from './pdas';
 
 export type EditionsDeployment =
   IdlAccounts<RaribleEditions>['editionsDeployment'];
@@ -13,6 +14,8 @@ export type EditionsControls =
 
 export type MinterStats = IdlAccounts<RaribleEditionsControls>['minterStats'];
 
+export type Hashlist = IdlAccounts<RaribleEditions>['hashlist'];
+
 export const decodeEditions =
   (program: Program<RaribleEditions>) =>
   (buffer: Buffer | undefined, pubkey: PublicKey) => {
@@ -57,6 +60,8 @@ export const logEditions = (editionsDecoded: {
         ? editionsDecoded.data.cosignerProgramId.toBase58()
         : null,
       tokensMinted: editionsDecoded.data.numberOfTokensIssued.toString(),
+      tokensBurned: editionsDecoded.data.numberOfTokensBurned.toString(),
+      status: Object.keys(editionsDecoded.data.status)[0],
       itemBaseName: editionsDecoded.data.itemBaseName,
       itemBaseUri: editionsDecoded.data.itemBaseUri,
       itemNameIsTemplate: editionsDecoded.data.itemNameIsTemplate,
@@ -122,6 +127,8 @@ export const logEditionsControls = (editionsControlsDecoded: {
       priceToken: item.priceToken ? item.priceToken.toBase58() : null,
       isPrivate: item.isPrivate,
       merkleRoot: item.merkleRoot ? JSON.stringify(item.merkleRoot) : null,
+      active: item.active,
+      removed: item.removed,
     })),
   });
 };
@@ -226,3 +233,38 @@ export const logMinterStatsPhase = (minterStatsDecoded: {
     },
   });
 };
+
+// Walks the original hashlist and then every hashlist page in order
+export const getHashlistEntries = async (
+  connection: Connection,
+  editionsPda: PublicKey,
+  editionsProgram: Program<RaribleEditions>
+) => {
+  const coder = new BorshCoder(editionsProgram.idl);
+  const editions = await getEditions(connection, editionsPda, editionsProgram);
+
+  const hashlistPda = getHashlistPda(editionsPda, editionsProgram.programId);
+  const hashlistAccountInfo = await connection.getAccountInfo(hashlistPda);
+  if (!hashlistAccountInfo) {
+    throw new Error('Hashlist account not found');
+  }
+  const entries = [
+    ...coder.accounts.decode<Hashlist>('hashlist', hashlistAccountInfo.data).issues,
+  ];
+
+  const pagedCount = Math.max(
+    editions.data.numberOfTokensIssued.toNumber() - entries.length,
+    0
+  );
+  const pageCount = Math.ceil(pagedCount / HASHLIST_PAGE_SIZE);
+  for (let pageIndex = 0; pageIndex < pageCount; pageIndex++) {
+    const pagePda = getHashlistPagePda(editionsPda, pageIndex, editionsProgram.programId);
+    const pageAccountInfo = await connection.getAccountInfo(pagePda);
+    if (!pageAccountInfo) {
+      throw new Error(`Hashlist page ${pageIndex} not found`);
+    }
+    entries.push(...coder.accounts.decode<Hashlist>('hashlist', pageAccountInfo.data).issues);
+  }
+
+  return entries;
+};
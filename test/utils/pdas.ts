@@ -73,3 +73,77 @@ export const getMinterStatsPhasePda = (
     editionsControlsProgramId
   )[0];
 };
+
+export const getTokenIndicesPda = (
+  deployment: PublicKey,
+  editionsProgramId: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('token_indices'), deployment.toBuffer()],
+    editionsProgramId
+  )[0];
+};
+
+export const HASHLIST_PAGE_SIZE = 1000;
+
+export const getHashlistPagePda = (
+  deployment: PublicKey,
+  pageIndex: number,
+  editionsProgramId: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('hashlist'),
+      deployment.toBuffer(),
+      toBufferLE(BigInt(pageIndex), 4),
+    ],
+    editionsProgramId
+  )[0];
+};
+
+export const getApproveAccountPda = (
+  mint: PublicKey,
+  editionsProgramId: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('approve-account'), mint.toBuffer()],
+    editionsProgramId
+  )[0];
+};
+
+export const getRoyaltyConfigPda = (
+  deployment: PublicKey,
+  editionsProgramId: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('royalty_config'), deployment.toBuffer()],
+    editionsProgramId
+  )[0];
+};
+
+export const getExtraMetasPda = (
+  mint: PublicKey,
+  editionsProgramId: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('extra-account-metas'), mint.toBuffer()],
+    editionsProgramId
+  )[0];
+};
+
+export const getGateMarkerPda = (
+  editionsControls: PublicKey,
+  phaseIndex: number,
+  gateMint: PublicKey,
+  editionsControlsProgramId: PublicKey
+) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('gate_marker'),
+      editionsControls.toBuffer(),
+      toBufferLE(BigInt(phaseIndex), 4),
+      gateMint.toBuffer(),
+    ],
+    editionsControlsProgramId
+  )[0];
+};
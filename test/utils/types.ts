@@ -42,4 +42,16 @@ export interface PhaseConfig {
   priceToken: PublicKey;
   isPrivate: boolean;
   merkleRoot: Buffer | null;
+  dutchAuction: {
+    floorPrice: anchor.BN;
+    stepSeconds: anchor.BN;
+    priceDecrement: anchor.BN;
+    rebate: boolean;
+  } | null;
+  tokenGate: {
+    gateType: { groupMember: {} } | { tokenBalance: {} } | { burnEdition: {} } | { burnToken: {} };
+    gateKey: PublicKey;
+    minBalance: anchor.BN;
+    singleUse: boolean;
+  } | null;
 }